@@ -0,0 +1,43 @@
+#ifndef LAMINATIONS_H
+#define LAMINATIONS_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef struct LamAlgebra LamAlgebra;
+typedef struct LamPoint LamPoint;
+
+/* Message of the last failed call on this thread, or NULL. Owned by the library. Calls that
+ * panic record the panic here and return NULL, SIZE_MAX, 2 or NaN. */
+const char *lam_last_error(void);
+
+/* Returns NULL (see lam_last_error) if the base is invalid. */
+LamAlgebra *lam_algebra_new(uint16_t base);
+void lam_algebra_free(LamAlgebra *algebra);
+
+/* Returns NULL (see lam_last_error) if the string is not a valid n-ary expansion. */
+LamPoint *lam_point_parse(const LamAlgebra *algebra, const char *s);
+void lam_point_free(LamPoint *point);
+
+LamPoint *lam_point_map(const LamAlgebra *algebra, const LamPoint *point);
+/* 0 if the point is strictly preperiodic, SIZE_MAX if the call panicked. */
+size_t lam_point_period(const LamAlgebra *algebra, const LamPoint *point);
+/* -1, 0 or 1, or 2 if the call panicked. */
+int lam_point_cmp(const LamAlgebra *algebra, const LamPoint *a, const LamPoint *b);
+/* NaN if the call panicked. */
+double lam_point_to_float(const LamAlgebra *algebra, const LamPoint *point);
+
+/* Returned strings must be released with lam_string_free. */
+char *lam_point_format(const LamAlgebra *algebra, const LamPoint *point);
+char *lam_point_format_fraction(const LamAlgebra *algebra, const LamPoint *point);
+void lam_string_free(char *s);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
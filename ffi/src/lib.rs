@@ -0,0 +1,248 @@
+use laminations_lib_rs::laminations::{DefaultAlgebra, UnitFraction, UnitNumber};
+use std::cell::RefCell;
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_int};
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+fn set_last_error(error: String) {
+    let error = CString::new(error).unwrap_or_else(|_| CString::new("invalid error").unwrap());
+    LAST_ERROR.with(|last_error| *last_error.borrow_mut() = Some(error));
+}
+
+// Runs the body of an exported function. Unwinding into C is undefined behaviour, so a panic is
+// recorded as the last error and the function returns `on_panic` instead. Handles the body was
+// using may be left in an inconsistent state.
+fn catch_panic<T, F: FnOnce() -> T>(on_panic: T, body: F) -> T {
+    panic::catch_unwind(AssertUnwindSafe(body)).unwrap_or_else(|payload| {
+        let message = match payload.downcast_ref::<&str>() {
+            Some(message) => message.to_string(),
+            None => match payload.downcast_ref::<String>() {
+                Some(message) => message.clone(),
+                None => "unknown panic".to_string(),
+            },
+        };
+        set_last_error(format!("panic: {}", message));
+        on_panic
+    })
+}
+
+fn into_handle<T>(result: Result<T, String>) -> *mut T {
+    match result {
+        Ok(value) => Box::into_raw(Box::new(value)),
+        Err(error) => {
+            set_last_error(error);
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Returns the message of the last failed call on this thread, or null. The string is owned by
+/// the library and stays valid until the next failing call on the same thread.
+#[no_mangle]
+pub extern "C" fn lam_last_error() -> *const c_char {
+    catch_panic(ptr::null(), || {
+        LAST_ERROR.with(|last_error| match &*last_error.borrow() {
+            Some(error) => error.as_ptr(),
+            None => ptr::null(),
+        })
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn lam_algebra_new(base: u16) -> *mut DefaultAlgebra {
+    catch_panic(ptr::null_mut(), || into_handle(DefaultAlgebra::try_new(base)))
+}
+
+/// # Safety
+/// `algebra` must be null or a handle returned by `lam_algebra_new` that has not been freed.
+#[no_mangle]
+pub unsafe extern "C" fn lam_algebra_free(algebra: *mut DefaultAlgebra) {
+    catch_panic((), || {
+        if !algebra.is_null() {
+            drop(Box::from_raw(algebra));
+        }
+    })
+}
+
+/// # Safety
+/// `algebra` must be a live algebra handle and `s` a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn lam_point_parse(
+    algebra: *const DefaultAlgebra,
+    s: *const c_char,
+) -> *mut UnitFraction {
+    catch_panic(ptr::null_mut(), || {
+        let s = match CStr::from_ptr(s).to_str() {
+            Ok(s) => s,
+            Err(_) => return into_handle(Err("input is not valid UTF-8".to_string())),
+        };
+        into_handle((*algebra).parse(s))
+    })
+}
+
+/// # Safety
+/// `point` must be null or a point handle that has not been freed.
+#[no_mangle]
+pub unsafe extern "C" fn lam_point_free(point: *mut UnitFraction) {
+    catch_panic((), || {
+        if !point.is_null() {
+            drop(Box::from_raw(point));
+        }
+    })
+}
+
+/// # Safety
+/// `algebra` and `point` must be live handles.
+#[no_mangle]
+pub unsafe extern "C" fn lam_point_map(
+    algebra: *const DefaultAlgebra,
+    point: *const UnitFraction,
+) -> *mut UnitFraction {
+    catch_panic(ptr::null_mut(), || into_handle(Ok((*algebra).map(&*point))))
+}
+
+/// Returns the period of `point`, or 0 if it is strictly preperiodic. Returns `usize::MAX` if the
+/// call panicked.
+///
+/// # Safety
+/// `algebra` and `point` must be live handles.
+#[no_mangle]
+pub unsafe extern "C" fn lam_point_period(
+    algebra: *const DefaultAlgebra,
+    point: *const UnitFraction,
+) -> usize {
+    catch_panic(usize::MAX, || (*algebra).period(&*point).unwrap_or(0))
+}
+
+/// Returns -1, 0 or 1 as `a` is less than, equal to or greater than `b`, or 2 if the call
+/// panicked.
+///
+/// # Safety
+/// `algebra`, `a` and `b` must be live handles.
+#[no_mangle]
+pub unsafe extern "C" fn lam_point_cmp(
+    algebra: *const DefaultAlgebra,
+    a: *const UnitFraction,
+    b: *const UnitFraction,
+) -> c_int {
+    catch_panic(2, || (*algebra).cmp(&*a, &*b) as c_int)
+}
+
+/// Returns NaN if the call panicked.
+///
+/// # Safety
+/// `algebra` and `point` must be live handles.
+#[no_mangle]
+pub unsafe extern "C" fn lam_point_to_float(
+    algebra: *const DefaultAlgebra,
+    point: *const UnitFraction,
+) -> f64 {
+    catch_panic(f64::NAN, || (*point).to_float((*algebra).base))
+}
+
+/// Formats `point` as n-ary digits. The result must be released with `lam_string_free`.
+///
+/// # Safety
+/// `algebra` and `point` must be live handles.
+#[no_mangle]
+pub unsafe extern "C" fn lam_point_format(
+    algebra: *const DefaultAlgebra,
+    point: *const UnitFraction,
+) -> *mut c_char {
+    catch_panic(ptr::null_mut(), || into_c_string((*algebra).format(&*point)))
+}
+
+/// Formats `point` as "p/q". The result must be released with `lam_string_free`.
+///
+/// # Safety
+/// `algebra` and `point` must be live handles.
+#[no_mangle]
+pub unsafe extern "C" fn lam_point_format_fraction(
+    algebra: *const DefaultAlgebra,
+    point: *const UnitFraction,
+) -> *mut c_char {
+    catch_panic(ptr::null_mut(), || into_c_string((*algebra).format_fraction(&*point)))
+}
+
+/// # Safety
+/// `s` must be null or a string returned by this library that has not been freed.
+#[no_mangle]
+pub unsafe extern "C" fn lam_string_free(s: *mut c_char) {
+    catch_panic((), || {
+        if !s.is_null() {
+            drop(CString::from_raw(s));
+        }
+    })
+}
+
+fn into_c_string(s: String) -> *mut c_char {
+    CString::new(s).unwrap().into_raw()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    unsafe fn read_string(s: *mut c_char) -> String {
+        let result = CStr::from_ptr(s).to_str().unwrap().to_string();
+        lam_string_free(s);
+        result
+    }
+
+    #[test]
+    fn round_trip() {
+        unsafe {
+            let algebra = lam_algebra_new(3);
+            let point = lam_point_parse(algebra, CString::new("2_1").unwrap().as_ptr());
+            assert!(!point.is_null());
+
+            assert_eq!(read_string(lam_point_format_fraction(algebra, point)), "5/6");
+            assert_eq!(lam_point_to_float(algebra, point), 5.0 / 6.0);
+            assert_eq!(lam_point_period(algebra, point), 0);
+
+            let mapped = lam_point_map(algebra, point);
+            assert_eq!(read_string(lam_point_format(algebra, mapped)), "_1");
+            assert_eq!(lam_point_period(algebra, mapped), 1);
+            assert_eq!(lam_point_cmp(algebra, point, mapped), 1);
+            assert_eq!(lam_point_cmp(algebra, mapped, point), -1);
+
+            lam_point_free(mapped);
+            lam_point_free(point);
+            lam_algebra_free(algebra);
+        }
+    }
+
+    #[test]
+    fn reports_errors() {
+        unsafe {
+            assert!(lam_algebra_new(1).is_null());
+            assert!(!lam_last_error().is_null());
+
+            let algebra = lam_algebra_new(2);
+            let point = lam_point_parse(algebra, CString::new("1_0_1").unwrap().as_ptr());
+            assert!(point.is_null());
+            assert_eq!(
+                CStr::from_ptr(lam_last_error()).to_str().unwrap(),
+                "`1_0_1` contains more than one underscore"
+            );
+            lam_algebra_free(algebra);
+        }
+    }
+
+    #[test]
+    fn catches_panics() {
+        assert_eq!(catch_panic(7, || 1), 1);
+        assert_eq!(catch_panic(ptr::null_mut::<u8>(), || panic!("broken")), ptr::null_mut());
+        let message = unsafe { CStr::from_ptr(lam_last_error()) };
+        assert_eq!(message.to_str().unwrap(), "panic: broken");
+
+        assert_eq!(catch_panic(2, || panic!("{} failed", "lam_point_cmp")), 2);
+        let message = unsafe { CStr::from_ptr(lam_last_error()) };
+        assert_eq!(message.to_str().unwrap(), "panic: lam_point_cmp failed");
+    }
+}
@@ -0,0 +1,509 @@
+use laminations_lib_rs::laminations::{
+    DefaultAlgebra, MinorLeaf, SvgOptions, UnitFraction, UnitNumber,
+};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::env;
+use std::io::{self, BufRead, Write};
+use std::process;
+
+const USAGE: &str = "usage: laminate <command> [options]
+
+commands:
+    parse --base <base> <angle>...    print the fraction and decimal value of n-ary angles
+    orbit --base <base> --steps <steps> <angle>...
+                                      print forward iterates and the preperiod and period
+    companion <angle>                 print the companion angle, minor and major leaves, period
+                                      and rotation number of a periodic binary angle
+    qml --max-denominator <q> --format <json|svg>
+                                      print the minor leaves of the quadratic minor lamination
+                                      whose angles have denominator 2^n - 1 <= q
+    render --base <base> --size <size>
+                                      draw the leaves read from stdin, two angles per line,
+                                      as an SVG
+    repl --base <base>                start an interactive session
+    help                              print this message";
+
+struct Args {
+    flags: HashMap<String, String>,
+    positional: Vec<String>,
+}
+
+impl Args {
+    fn parse(args: &[String], known_flags: &[&str]) -> Result<Args, String> {
+        let mut flags = HashMap::new();
+        let mut positional = vec![];
+        let mut iter = args.iter();
+
+        while let Some(arg) = iter.next() {
+            match arg.strip_prefix("--") {
+                Some(name) => {
+                    if !known_flags.contains(&name) {
+                        return Err(format!("unknown flag `--{}`", name));
+                    }
+                    let value = iter
+                        .next()
+                        .ok_or_else(|| format!("`--{}` expects a value", name))?;
+                    flags.insert(name.to_string(), value.clone());
+                }
+                None => positional.push(arg.clone()),
+            }
+        }
+
+        Ok(Args { flags, positional })
+    }
+
+    fn flag<T: std::str::FromStr>(&self, name: &str, default: T) -> Result<T, String> {
+        match self.flags.get(name) {
+            Some(value) => value
+                .parse()
+                .map_err(|_| format!("`--{}`: `{}` is not a valid value", name, value)),
+            None => Ok(default),
+        }
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    if let Err(message) = run(&args) {
+        eprintln!("laminate: {}", message);
+        process::exit(1);
+    }
+}
+
+type Command = fn(&Args) -> Result<(), String>;
+
+fn run(args: &[String]) -> Result<(), String> {
+    let (name, rest) = match args.split_first() {
+        Some(split) => split,
+        None => return Err(USAGE.to_string()),
+    };
+
+    let (command, known_flags): (Command, &[&str]) = match name.as_str() {
+        "parse" => (parse, &["base"]),
+        "orbit" => (orbit, &["base", "steps"]),
+        "companion" => (companion, &[]),
+        "qml" => (qml, &["max-denominator", "format"]),
+        "render" => (render, &["base", "size"]),
+        "repl" => (repl, &["base"]),
+        "help" | "--help" | "-h" => {
+            println!("{}", USAGE);
+            return Ok(());
+        }
+        other => return Err(format!("unknown command `{}`\n\n{}", other, USAGE)),
+    };
+    command(&Args::parse(rest, known_flags)?)
+}
+
+fn parse(args: &Args) -> Result<(), String> {
+    let algebra = DefaultAlgebra::try_new(args.flag("base", 2)?)?;
+
+    if args.positional.is_empty() {
+        return Err("parse: expected at least one angle".to_string());
+    }
+
+    for angle in &args.positional {
+        let point = algebra.parse(angle)?;
+        println!(
+            "{}\t{}\t{}",
+            angle,
+            point.to_rational(algebra.base),
+            point.to_float(algebra.base)
+        );
+    }
+
+    Ok(())
+}
+
+fn orbit(args: &Args) -> Result<(), String> {
+    let algebra = DefaultAlgebra::try_new(args.flag("base", 2)?)?;
+    let steps: usize = args.flag("steps", 10)?;
+
+    if args.positional.is_empty() {
+        return Err("orbit: expected at least one angle".to_string());
+    }
+
+    for angle in &args.positional {
+        let start = algebra.parse(angle)?;
+        let mut point = start.clone();
+        for step in 0..=steps {
+            println!("{}\t{}\t{}", step, algebra.format(&point), algebra.format_fraction(&point));
+            point = algebra.map(&point);
+        }
+
+        let orbit = algebra.orbit(&start);
+        let image = algebra.map(orbit.last().unwrap());
+        let preperiod = orbit.iter().position(|x| *x == image).unwrap();
+        println!("preperiod {}\tperiod {}", preperiod, orbit.len() - preperiod);
+    }
+
+    Ok(())
+}
+
+fn companion(args: &Args) -> Result<(), String> {
+    let algebra = DefaultAlgebra::new(2);
+    let angle = match args.positional.as_slice() {
+        [angle] => algebra.parse(angle)?,
+        _ => return Err("companion: expected one angle".to_string()),
+    };
+    let period = algebra
+        .period(&angle)
+        .ok_or_else(|| format!("companion: {} is not periodic", algebra.format(&angle)))?;
+
+    let companion = algebra.companion(&angle)?;
+    let (lower, upper) = match algebra.cmp(&angle, &companion) {
+        Ordering::Greater => (companion.clone(), angle.clone()),
+        _ => (angle.clone(), companion.clone()),
+    };
+
+    let mut lower_preimages = algebra.preimages(&lower)?;
+    let mut upper_preimages = algebra.preimages(&upper)?;
+    lower_preimages.sort_by(|a, b| algebra.cmp(a, b));
+    upper_preimages.sort_by(|a, b| algebra.cmp(a, b));
+
+    println!("angle\t{}", algebra.format(&angle));
+    println!("companion\t{}", algebra.format(&companion));
+    println!("minor\t{}\t{}", algebra.format(&lower), algebra.format(&upper));
+    let mut majors = vec![
+        (&lower_preimages[0], &upper_preimages[1]),
+        (&upper_preimages[0], &lower_preimages[1]),
+    ];
+    majors.dedup();
+    for (a, b) in majors {
+        println!("major\t{}\t{}", algebra.format(a), algebra.format(b));
+    }
+    println!("period\t{}", period);
+    match algebra.rotation_number(&angle) {
+        Some(rotation_number) => println!("rotation\t{}", rotation_number),
+        None => println!("rotation\tnone"),
+    }
+
+    Ok(())
+}
+
+fn qml(args: &Args) -> Result<(), String> {
+    let algebra = DefaultAlgebra::new(2);
+    let max_denominator: u128 = args.flag("max-denominator", 255)?;
+    let format: String = args.flag("format", "json".to_string())?;
+
+    let mut max_period = 1;
+    while max_period < 127 && (1u128 << (max_period + 1)) - 1 <= max_denominator {
+        max_period += 1;
+    }
+    let minors = algebra.minor_leaves_up_to(max_period)?;
+
+    match format.as_str() {
+        "json" => print_qml_json(&algebra, &minors),
+        "svg" => print!("{}", algebra.render_svg(&minors, &SvgOptions::default())),
+        other => return Err(format!("qml: unknown format `{}`", other)),
+    }
+    Ok(())
+}
+
+fn print_qml_json(algebra: &DefaultAlgebra, minors: &[(usize, MinorLeaf)]) {
+    println!("[");
+    for (index, (period, (lower, upper))) in minors.iter().enumerate() {
+        let separator = if index + 1 < minors.len() { "," } else { "" };
+        println!(
+            "  {{\"period\": {}, \"lower\": \"{}\", \"upper\": \"{}\", \
+             \"lower_fraction\": \"{}\", \"upper_fraction\": \"{}\"}}{}",
+            period,
+            algebra.format(lower),
+            algebra.format(upper),
+            algebra.format_fraction(lower),
+            algebra.format_fraction(upper),
+            separator
+        );
+    }
+    println!("]");
+}
+
+fn render(args: &Args) -> Result<(), String> {
+    let algebra = DefaultAlgebra::try_new(args.flag("base", 2)?)?;
+    let options = SvgOptions {
+        size: args.flag("size", SvgOptions::default().size)?,
+        ..SvgOptions::default()
+    };
+
+    let stdin = io::stdin();
+    print!("{}", render_leaves(&algebra, stdin.lock(), &options)?);
+    Ok(())
+}
+
+fn render_leaves<R: BufRead>(
+    algebra: &DefaultAlgebra,
+    reader: R,
+    options: &SvgOptions,
+) -> Result<String, String> {
+    let leaves = algebra
+        .read_lines(reader)
+        .map(|leaf| leaf.map(|leaf| (0, leaf)))
+        .collect::<Result<Vec<_>, String>>()
+        .map_err(|error| format!("render: {}", error))?;
+    Ok(algebra.render_svg(&leaves, options))
+}
+
+const REPL_HELP: &str = "expressions:
+    <angle>                  a point, e.g. _001
+    <a> <b>                  a leaf between two points
+    map <expr>               the image of a point or leaf
+    <name>                   a stored value
+
+commands:
+    <name> = <expression>    store a value
+    preimages <expr>         print the preimages of a point
+    orbit <expr>             print the forward orbit of a point
+    period <expr>            print the period of a point
+    render                   print an SVG of the stored leaves
+    base <base>              switch base, clearing stored values
+    vars                     print stored values
+    help                     print this message
+    quit                     end the session";
+
+#[derive(Clone)]
+enum Value {
+    Point(UnitFraction),
+    Leaf(UnitFraction, UnitFraction),
+}
+
+struct Session {
+    algebra: DefaultAlgebra,
+    values: HashMap<String, Value>,
+}
+
+impl Session {
+    fn new(base: u16) -> Result<Session, String> {
+        Ok(Session {
+            algebra: DefaultAlgebra::try_new(base)?,
+            values: HashMap::new(),
+        })
+    }
+
+    fn show(&self, value: &Value) -> String {
+        match value {
+            Value::Point(x) => {
+                format!("{}\t{}", self.algebra.format(x), self.algebra.format_fraction(x))
+            }
+            Value::Leaf(a, b) => format!("{} {}", self.algebra.format(a), self.algebra.format(b)),
+        }
+    }
+
+    fn show_points(&self, points: Vec<UnitFraction>) -> Vec<String> {
+        points.into_iter().map(|x| self.show(&Value::Point(x))).collect()
+    }
+
+    fn point(&self, token: &str) -> Result<UnitFraction, String> {
+        match self.values.get(token) {
+            Some(Value::Point(x)) => Ok(x.clone()),
+            Some(Value::Leaf(..)) => Err(format!("`{}` is a leaf, not a point", token)),
+            None => self.algebra.parse(token),
+        }
+    }
+
+    fn value(&self, tokens: &[&str]) -> Result<Value, String> {
+        match tokens {
+            ["map", rest @ ..] => match self.value(rest)? {
+                Value::Point(x) => Ok(Value::Point(self.algebra.map(&x))),
+                Value::Leaf(a, b) => Ok(Value::Leaf(self.algebra.map(&a), self.algebra.map(&b))),
+            },
+            [token] => match self.values.get(*token) {
+                Some(value) => Ok(value.clone()),
+                None => Ok(Value::Point(self.algebra.parse(token)?)),
+            },
+            [a, b] => Ok(Value::Leaf(self.point(a)?, self.point(b)?)),
+            _ => Err("expected a point, a leaf or `map <expression>`".to_string()),
+        }
+    }
+
+    fn point_value(&self, tokens: &[&str]) -> Result<UnitFraction, String> {
+        match self.value(tokens)? {
+            Value::Point(x) => Ok(x),
+            Value::Leaf(..) => Err("expected a point, not a leaf".to_string()),
+        }
+    }
+
+    fn eval(&mut self, line: &str) -> Result<Vec<String>, String> {
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        match tokens.as_slice() {
+            [] => Ok(vec![]),
+            ["help"] => Ok(vec![REPL_HELP.to_string()]),
+            ["vars"] => {
+                let mut names: Vec<&String> = self.values.keys().collect();
+                names.sort();
+                Ok(names
+                    .into_iter()
+                    .map(|name| format!("{} = {}", name, self.show(&self.values[name])))
+                    .collect())
+            }
+            ["render"] => {
+                let mut names: Vec<&String> = self.values.keys().collect();
+                names.sort();
+                let leaves: Vec<(usize, (UnitFraction, UnitFraction))> = names
+                    .into_iter()
+                    .filter_map(|name| match &self.values[name] {
+                        Value::Leaf(a, b) => Some((0, (a.clone(), b.clone()))),
+                        Value::Point(_) => None,
+                    })
+                    .collect();
+                let svg = self.algebra.render_svg(&leaves, &SvgOptions::default());
+                Ok(vec![svg.trim_end().to_string()])
+            }
+            ["base", base] => {
+                let base = base.parse().map_err(|_| format!("`{}` is not a valid base", base))?;
+                self.algebra = DefaultAlgebra::try_new(base)?;
+                self.values.clear();
+                Ok(vec![])
+            }
+            ["preimages", rest @ ..] => {
+                let x = self.point_value(rest)?;
+                Ok(self.show_points(self.algebra.preimages(&x)?))
+            }
+            ["orbit", rest @ ..] => {
+                let x = self.point_value(rest)?;
+                Ok(self.show_points(self.algebra.orbit(&x)))
+            }
+            ["period", rest @ ..] => {
+                let x = self.point_value(rest)?;
+                match self.algebra.period(&x) {
+                    Some(period) => Ok(vec![period.to_string()]),
+                    None => Ok(vec!["not periodic".to_string()]),
+                }
+            }
+            [name, "=", rest @ ..] => {
+                let value = self.value(rest)?;
+                let shown = self.show(&value);
+                self.values.insert(name.to_string(), value);
+                Ok(vec![format!("{} = {}", name, shown)])
+            }
+            _ => Ok(vec![self.show(&self.value(&tokens)?)]),
+        }
+    }
+}
+
+fn repl(args: &Args) -> Result<(), String> {
+    let mut session = Session::new(args.flag("base", 2)?)?;
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+
+    loop {
+        print!("> ");
+        io::stdout().flush().map_err(|error| error.to_string())?;
+        let line = match lines.next() {
+            Some(line) => line.map_err(|error| error.to_string())?,
+            None => return Ok(()),
+        };
+        if matches!(line.trim(), "quit" | "exit") {
+            return Ok(());
+        }
+        match session.eval(&line) {
+            Ok(output) => output.iter().for_each(|line| println!("{}", line)),
+            Err(error) => println!("error: {}", error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{render_leaves, Args, Session};
+    use laminations_lib_rs::laminations::{DefaultAlgebra, SvgOptions};
+
+    fn strings(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn parses_args() {
+        let args = Args::parse(&strings(&["--base", "3", "_01", "1_2"]), &["base"]).unwrap();
+        assert_eq!(args.positional, strings(&["_01", "1_2"]));
+        assert_eq!(args.flag("base", 2), Ok(3u16));
+        assert_eq!(args.flag("steps", 10), Ok(10usize));
+
+        let args = Args::parse(&strings(&["--base", "three"]), &["base"]).unwrap();
+        assert_eq!(
+            args.flag("base", 2u16),
+            Err("`--base`: `three` is not a valid value".to_string())
+        );
+
+        assert_eq!(
+            Args::parse(&strings(&["_01", "--base"]), &["base"]).err(),
+            Some("`--base` expects a value".to_string())
+        );
+        assert_eq!(
+            Args::parse(&strings(&["--bsae", "3"]), &["base"]).err(),
+            Some("unknown flag `--bsae`".to_string())
+        );
+        assert_eq!(
+            Args::parse(&strings(&["--steps", "3"]), &["base"]).err(),
+            Some("unknown flag `--steps`".to_string())
+        );
+    }
+
+    #[test]
+    fn renders_leaves() {
+        let binary = DefaultAlgebra::new(2);
+        let options = SvgOptions {
+            size: 100.0,
+            ..SvgOptions::default()
+        };
+
+        let svg = render_leaves(&binary, "_ 1\n\n01 11\n".as_bytes(), &options).unwrap();
+        assert!(svg.starts_with("<svg") && svg.ends_with("</svg>\n"));
+        assert!(svg.contains("<line x1=\"100.000\" y1=\"50.000\" x2=\"0.000\" y2=\"50.000\""));
+        assert_eq!(svg.matches("<line").count(), 2);
+
+        assert_eq!(
+            render_leaves(&binary, "_01 _10 _11\n".as_bytes(), &options),
+            Err("render: line 1: expected 2 points, found 3".to_string())
+        );
+    }
+
+    #[test]
+    fn evaluates_points_and_leaves() {
+        let mut session = Session::new(2).unwrap();
+        let mut eval = |line: &str| session.eval(line);
+
+        assert_eq!(eval(""), Ok(vec![]));
+        assert_eq!(eval("_01"), Ok(strings(&["_01\t1/3"])));
+        assert_eq!(eval("map 0_01"), Ok(strings(&["_01\t1/3"])));
+        assert_eq!(eval("_01 _10"), Ok(strings(&["_01 _10"])));
+        assert_eq!(eval("map _01 _10"), Ok(strings(&["_10 _01"])));
+        assert_eq!(eval("x = _001"), Ok(strings(&["x = _001\t1/7"])));
+        assert_eq!(eval("l = x _010"), Ok(strings(&["l = _001 _010"])));
+        assert_eq!(eval("map l"), Ok(strings(&["_010 _100"])));
+        assert_eq!(eval("period x"), Ok(strings(&["3"])));
+        assert_eq!(eval("period 0_01"), Ok(strings(&["not periodic"])));
+        assert_eq!(eval("orbit 0_01"), Ok(strings(&["0_01\t1/6", "_01\t1/3", "_10\t2/3"])));
+        assert_eq!(eval("preimages _01"), Ok(strings(&["0_01\t1/6", "_10\t2/3"])));
+        assert_eq!(eval("vars"), Ok(strings(&["l = _001 _010", "x = _001\t1/7"])));
+
+
+        let svg = eval("render").unwrap();
+        assert_eq!(svg.len(), 1);
+        assert!(svg[0].starts_with("<svg") && svg[0].ends_with("</svg>"));
+        assert_eq!(svg[0].matches("<line").count(), 1);
+        assert!(svg[0].contains("<line x1=\"649.396\" y1=\"87.267\""));
+
+        assert_eq!(eval("base 3"), Ok(vec![]));
+        assert_eq!(eval("render").unwrap()[0].matches("<line").count(), 0);
+        assert_eq!(eval("vars"), Ok(vec![]));
+        assert_eq!(eval("_1"), Ok(strings(&["_1\t1/2"])));
+    }
+
+    #[test]
+    fn reports_errors() {
+        let mut session = Session::new(2).unwrap();
+        session.eval("l = _01 _10").unwrap();
+
+        assert_eq!(session.eval("l _01"), Err("`l` is a leaf, not a point".to_string()));
+        assert_eq!(session.eval("period l"), Err("expected a point, not a leaf".to_string()));
+        assert_eq!(
+            session.eval("_01 _10 _11"),
+            Err("expected a point, a leaf or `map <expression>`".to_string())
+        );
+        assert_eq!(session.eval("base two"), Err("`two` is not a valid base".to_string()));
+        assert!(session.eval("_0z").is_err());
+        assert!(session.eval("base 1").is_err());
+        assert!(Session::new(0).is_err());
+    }
+}
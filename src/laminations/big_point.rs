@@ -0,0 +1,166 @@
+use super::{digits_from_rational, parse_digit_parts, ExactUnitNumber, UnitNumber};
+use num::bigint::BigInt;
+use num::rational::{BigRational, Ratio};
+use num::pow::pow;
+use num::{One, ToPrimitive, Zero};
+use std::cmp::Ordering;
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BigPoint(BigRational);
+
+impl BigPoint {
+    pub fn new(value: BigRational) -> BigPoint {
+        let fraction = value.fract();
+        if fraction < BigRational::zero() {
+            return BigPoint(fraction + BigRational::one());
+        }
+        BigPoint(fraction)
+    }
+
+    pub fn value(&self) -> &BigRational {
+        &self.0
+    }
+}
+
+impl ExactUnitNumber for BigPoint {}
+
+impl UnitNumber for BigPoint {
+    fn parse_nary(base: u16, s: &str) -> Result<Self, String> {
+        let (exact_digits, repeating_digits) = parse_digit_parts(base, s)?;
+        let base = BigInt::from(base);
+
+        let mut repeating_denominator = pow(base.clone(), repeating_digits.len()) - 1u8;
+        if repeating_denominator.is_zero() {
+            repeating_denominator = BigInt::one();
+        }
+        let denominator = &repeating_denominator * pow(base.clone(), exact_digits.len());
+        let numerator = repeating_denominator * big_from_digits(&base, &exact_digits)
+            + big_from_digits(&base, &repeating_digits);
+
+        Ok(BigPoint::new(BigRational::new(numerator, denominator)))
+    }
+
+    // Values beyond u128 only have a BigRational; see to_big_rational.
+    fn to_rational(&self, _base: u16) -> Ratio<u128> {
+        match (self.0.numer().to_u128(), self.0.denom().to_u128()) {
+            (Some(numerator), Some(denominator)) => Ratio::new(numerator, denominator),
+            _ => panic!("{} does not fit in a Ratio<u128>", self.0),
+        }
+    }
+
+    fn to_big_rational(&self, _base: u16) -> BigRational {
+        self.0.clone()
+    }
+
+    fn to_digits(&self, base: u16) -> (Vec<u16>, Vec<u16>) {
+        digits_from_rational(base, &self.0)
+    }
+
+    fn to_float(&self, _base: u16) -> f64 {
+        self.0.to_f64().unwrap()
+    }
+
+    fn map(&self, base: u16) -> Self {
+        BigPoint::new(&self.0 * BigInt::from(base))
+    }
+
+    fn preimages(&self, base: u16) -> Result<Vec<Self>, String> {
+        Ok((0..base)
+            .map(|digit| BigPoint::new((&self.0 + BigInt::from(digit)) / BigInt::from(base)))
+            .collect())
+    }
+
+    fn cmp_in_base(&self, other: &Self, _base: u16) -> Ordering {
+        self.cmp(other)
+    }
+}
+
+fn big_from_digits(base: &BigInt, digits: &[u16]) -> BigInt {
+    digits
+        .iter()
+        .fold(BigInt::zero(), |sum, &digit| sum * base + digit)
+}
+
+impl_primitive_conversions!(
+    BigPoint,
+    zero: BigPoint::new(BigRational::zero()),
+    to_f64: |x| x.0.to_f64(),
+    from_f64: |n| BigRational::from_float(n).map(BigPoint::new),
+);
+
+#[cfg(test)]
+mod tests {
+    use super::super::LaminationAlgebra;
+    use super::*;
+    use num::{FromPrimitive, ToPrimitive};
+
+    #[test]
+    fn parse_big() {
+        let ternary = LaminationAlgebra::<BigPoint>::new(3);
+
+        assert_eq!(ternary.parse("1_021").unwrap().to_rational(3), Ratio::new(11, 26));
+        assert_eq!(ternary.parse("_2").unwrap(), ternary.parse("_").unwrap());
+        assert_eq!(ternary.parse("1_021").unwrap(), ternary.parse("_102").unwrap());
+        assert!(ternary.parse("1_0_2").is_err());
+    }
+
+    #[test]
+    fn exceeds_u128() {
+        let binary = LaminationAlgebra::<BigPoint>::new(2);
+        let period_200 = format!("_{}1", "0".repeat(199));
+        let point = binary.parse(&period_200).unwrap();
+
+        let denominator = pow(BigInt::from(2u8), 200) - 1u8;
+        assert_eq!(*point.value(), BigRational::new(BigInt::one(), denominator));
+        assert_eq!(binary.format(&point), period_200);
+        assert!(point.to_float(2) > 0.0);
+        assert!(point < binary.parse("_01").unwrap());
+    }
+
+    #[test]
+    fn num_traits() {
+        let tiny = BigPoint::from_f64(f64::MIN_POSITIVE).unwrap();
+        assert!(tiny.value().denom().bits() > 1000);
+        assert_eq!(BigPoint::from_f64(0.1).unwrap().to_f64(), Some(0.1));
+        assert_eq!(
+            BigPoint::from_f64(-0.25).unwrap(),
+            BigPoint::new(BigRational::new(BigInt::from(3), BigInt::from(4)))
+        );
+        assert_eq!(BigPoint::from_f64(f64::NAN), None);
+    }
+
+    #[test]
+    fn dynamics() {
+        let binary = LaminationAlgebra::<BigPoint>::new(2);
+        let point = binary.parse(&format!("_{}1", "0".repeat(199))).unwrap();
+
+        assert_eq!(binary.period(&point), Some(200));
+        assert_eq!(
+            binary.map(&point),
+            binary.parse(&format!("_{}10", "0".repeat(198))).unwrap()
+        );
+        for preimage in binary.preimages(&point).unwrap() {
+            assert_eq!(binary.map(&preimage), point);
+        }
+    }
+
+    #[test]
+    fn formats_values_beyond_u128() {
+        let binary = LaminationAlgebra::<BigPoint>::new(2);
+        let point = binary.parse(&format!("_{}1", "0".repeat(199))).unwrap();
+        let denominator = pow(BigInt::from(2u8), 200) - 1u8;
+
+        assert_eq!(binary.to_big_rational(&point), *point.value());
+        assert_eq!(binary.format_fraction(&point), format!("1/{}", denominator));
+        let table = binary.orbit_table(&point);
+        assert_eq!(table.lines().count(), 201);
+        assert!(table.contains(&format!("1/{}", denominator)));
+
+        let orbit = binary.orbit(&point);
+        let portrait = binary.orbit_portrait(&orbit[..1]).unwrap();
+        assert_eq!(portrait.valence(), 1);
+        let pair = [point.clone(), binary.parse(&format!("_{}10", "0".repeat(198))).unwrap()];
+        assert!(binary.orbit_portrait(&pair).is_err());
+        assert!(binary.in_qml(&pair[0], &pair[1]).is_ok());
+    }
+}
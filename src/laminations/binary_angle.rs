@@ -0,0 +1,363 @@
+use super::{parse_digit_parts, UnitFraction, ExactUnitNumber, UnitNumber};
+use num::integer::lcm;
+use num::rational::Ratio;
+use std::cmp::Ordering;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BinaryAngle {
+    exact_bits: u128,
+    exact_len: u8,
+    repeating_bits: u128,
+    repeating_len: u8,
+}
+
+// The longest angle whose denominator 2^e (2^r - 1) and numerator fit in u128, so the rational
+// view of every angle exists.
+const MAX_BITS: usize = 127;
+
+impl BinaryAngle {
+    pub fn new(exact_bits: u128, exact_len: u8, repeating_bits: u128, repeating_len: u8) -> Self {
+        BinaryAngle::try_new(exact_bits, exact_len, repeating_bits, repeating_len)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    pub fn try_new(
+        exact_bits: u128,
+        exact_len: u8,
+        repeating_bits: u128,
+        repeating_len: u8,
+    ) -> Result<Self, String> {
+        let total_len = exact_len as usize + repeating_len as usize;
+        if total_len > MAX_BITS {
+            return Err(format!("{} bits do not fit in a BinaryAngle", total_len));
+        }
+
+        let exact_bits = exact_bits & mask(exact_len);
+        let (mut repeating_bits, mut repeating_len) =
+            minimal_period(repeating_bits & mask(repeating_len), repeating_len);
+        let (mut exact_bits, mut exact_len) = (exact_bits, exact_len);
+
+        if repeating_len == 1 {
+            if repeating_bits == 1 {
+                exact_bits = exact_bits.wrapping_add(1) & mask(exact_len);
+            }
+            repeating_bits = 0;
+            repeating_len = 0;
+        }
+
+        while exact_len > 0 {
+            let last_exact_bit = exact_bits & 1;
+            if repeating_len == 0 {
+                if last_exact_bit != 0 {
+                    break;
+                }
+            } else {
+                if last_exact_bit != repeating_bits & 1 {
+                    break;
+                }
+                repeating_bits = (repeating_bits >> 1) | (last_exact_bit << (repeating_len - 1));
+            }
+            exact_bits >>= 1;
+            exact_len -= 1;
+        }
+
+        Ok(BinaryAngle {
+            exact_bits,
+            exact_len,
+            repeating_bits,
+            repeating_len,
+        })
+    }
+
+    pub fn exact_len(&self) -> u8 {
+        self.exact_len
+    }
+
+    pub fn repeating_len(&self) -> u8 {
+        self.repeating_len
+    }
+
+    pub fn bit(&self, index: usize) -> u16 {
+        let exact_len = self.exact_len as usize;
+        let repeating_len = self.repeating_len as usize;
+        if index < exact_len {
+            return ((self.exact_bits >> (exact_len - 1 - index)) & 1) as u16;
+        }
+        if repeating_len == 0 {
+            return 0;
+        }
+        let offset = (index - exact_len) % repeating_len;
+        ((self.repeating_bits >> (repeating_len - 1 - offset)) & 1) as u16
+    }
+
+    pub fn map(&self) -> BinaryAngle {
+        if self.exact_len > 0 {
+            return BinaryAngle {
+                exact_bits: self.exact_bits & mask(self.exact_len - 1),
+                exact_len: self.exact_len - 1,
+                ..*self
+            };
+        }
+        if self.repeating_len == 0 {
+            return *self;
+        }
+
+        let leading_bit = self.repeating_bits >> (self.repeating_len - 1);
+        BinaryAngle {
+            repeating_bits: ((self.repeating_bits << 1) & mask(self.repeating_len)) | leading_bit,
+            ..*self
+        }
+    }
+
+    pub fn preimages(&self) -> Result<[BinaryAngle; 2], String> {
+        let with_leading_bit = |bit: u128| {
+            BinaryAngle::try_new(
+                (bit << self.exact_len) | self.exact_bits,
+                self.exact_len + 1,
+                self.repeating_bits,
+                self.repeating_len,
+            )
+        };
+        Ok([with_leading_bit(0)?, with_leading_bit(1)?])
+    }
+
+    fn to_unit_fraction(self) -> UnitFraction {
+        UnitFraction::new(
+            self.exact_bits,
+            self.exact_len,
+            self.repeating_bits,
+            self.repeating_len,
+        )
+    }
+}
+
+fn mask(len: u8) -> u128 {
+    1u128.checked_shl(len as u32).unwrap_or(0).wrapping_sub(1)
+}
+
+fn minimal_period(bits: u128, len: u8) -> (u128, u8) {
+    for period in 1..len {
+        if !len.is_multiple_of(period) {
+            continue;
+        }
+        let rotated = ((bits << period) & mask(len)) | (bits >> (len - period));
+        if rotated == bits {
+            return (bits >> (len - period), period);
+        }
+    }
+    (bits, len)
+}
+
+impl ExactUnitNumber for BinaryAngle {}
+
+impl UnitNumber for BinaryAngle {
+    fn check_base(base: u16) -> Result<(), String> {
+        if base != 2 {
+            return Err(format!("BinaryAngle requires base 2, not {}", base));
+        }
+        Ok(())
+    }
+
+    fn parse_nary(base: u16, s: &str) -> Result<Self, String> {
+        Self::check_base(base)?;
+        let (exact_digits, repeating_digits) = parse_digit_parts(base, s)?;
+
+        let pack = |digits: &[u16]| -> Result<u128, String> {
+            if digits.len() > 128 {
+                return Err(format!("{}: more than 128 digits in one part", s));
+            }
+            digits.iter().try_fold(0, |bits, &digit| match digit {
+                0 | 1 => Ok((bits << 1) | digit as u128),
+                _ => Err(format!("{}: `{}` is not a binary digit", s, digit)),
+            })
+        };
+
+        BinaryAngle::try_new(
+            pack(&exact_digits)?,
+            exact_digits.len() as u8,
+            pack(&repeating_digits)?,
+            repeating_digits.len() as u8,
+        )
+        .map_err(|error| format!("{}: {}", s, error))
+    }
+
+    fn to_rational(&self, base: u16) -> Ratio<u128> {
+        self.to_unit_fraction().to_rational(base)
+    }
+
+    fn to_digits(&self, _base: u16) -> (Vec<u16>, Vec<u16>) {
+        let exact_len = self.exact_len as usize;
+        let repeating_len = self.repeating_len as usize;
+        (
+            (0..exact_len).map(|index| self.bit(index)).collect(),
+            (exact_len..exact_len + repeating_len)
+                .map(|index| self.bit(index))
+                .collect(),
+        )
+    }
+
+    fn map(&self, _base: u16) -> Self {
+        BinaryAngle::map(self)
+    }
+
+    fn preimages(&self, _base: u16) -> Result<Vec<Self>, String> {
+        Ok(BinaryAngle::preimages(self)?.to_vec())
+    }
+
+    fn cmp_in_base(&self, other: &Self, _base: u16) -> Ordering {
+        self.cmp(other)
+    }
+}
+
+impl_primitive_conversions!(
+    BinaryAngle,
+    zero: BinaryAngle::new(0, 0, 0, 0),
+    to_f64: |x| {
+        let exact_scale = 2f64.powi(x.exact_len as i32);
+        let repeating_scale = 2f64.powi(x.repeating_len as i32) - 1.0;
+        let repeating_value = if x.repeating_len == 0 {
+            0.0
+        } else {
+            x.repeating_bits as f64 / repeating_scale
+        };
+        Some((x.exact_bits as f64 + repeating_value) / exact_scale)
+    },
+    from_f64: |n| {
+        if !n.is_finite() {
+            return None;
+        }
+
+        let mut remaining = n.rem_euclid(1.0);
+        let mut bits = 0;
+        let mut len = 0;
+        while remaining != 0.0 {
+            if len as usize == MAX_BITS {
+                return None;
+            }
+            remaining *= 2.0;
+            bits = (bits << 1) | remaining.floor() as u128;
+            remaining = remaining.fract();
+            len += 1;
+        }
+        Some(BinaryAngle::new(bits, len, 0, 0))
+    },
+);
+
+impl Ord for BinaryAngle {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let exact_len = self.exact_len.max(other.exact_len) as usize;
+        let repeating_len = lcm(
+            self.repeating_len.max(1) as usize,
+            other.repeating_len.max(1) as usize,
+        );
+
+        (0..exact_len + repeating_len)
+            .map(|index| self.bit(index).cmp(&other.bit(index)))
+            .find(|ordering| *ordering != Ordering::Equal)
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for BinaryAngle {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::LaminationAlgebra;
+    use super::*;
+    use num::{FromPrimitive, ToPrimitive};
+    use std::convert::TryFrom;
+
+    type BinaryAlgebra = LaminationAlgebra<BinaryAngle>;
+
+    #[test]
+    fn parse_binary() {
+        let binary = BinaryAlgebra::new(2);
+
+        assert_eq!(binary.parse("1_001").unwrap(), BinaryAngle::new(1, 1, 1, 3));
+        assert_eq!(binary.parse("1_001").unwrap(), binary.parse("_100").unwrap());
+        assert_eq!(binary.parse("_0101").unwrap(), binary.parse("_01").unwrap());
+        assert_eq!(binary.parse("0_1").unwrap(), binary.parse("1").unwrap());
+        assert_eq!(binary.parse("_1").unwrap(), binary.parse("_").unwrap());
+        assert_eq!(binary.parse("0100_0").unwrap(), binary.parse("01").unwrap());
+
+        assert!(binary.parse("12").is_err());
+        assert!(BinaryAlgebra::try_new(3).is_err());
+    }
+
+    #[test]
+    fn matches_unit_fraction() {
+        let binary = BinaryAlgebra::new(2);
+
+        for s in &["_", "1", "011", "_001", "1_01", "0110_100", "_0101"] {
+            let angle = binary.parse(s).unwrap();
+            let fraction = UnitFraction::parse_nary(2, s).unwrap();
+            assert_eq!(angle.to_rational(2), fraction.to_rational(2));
+        }
+    }
+
+    #[test]
+    fn dynamics() {
+        let binary = BinaryAlgebra::new(2);
+
+        let a = binary.parse("1_001").unwrap();
+        assert_eq!(a.map(), binary.parse("_001").unwrap());
+        assert_eq!(a.map().map(), binary.parse("_010").unwrap());
+        assert_eq!(binary.parse("11").unwrap().map(), binary.parse("1").unwrap());
+        assert_eq!(binary.parse("1").unwrap().map(), binary.parse("_").unwrap());
+
+        let [left, right] = binary.parse("_01").unwrap().preimages().unwrap();
+        assert_eq!(left, binary.parse("0_01").unwrap());
+        assert_eq!(right, binary.parse("1_01").unwrap());
+        assert_eq!(right, binary.parse("_10").unwrap());
+
+        assert_eq!(binary.period(&binary.parse("11_001").unwrap()), None);
+        assert_eq!(binary.period(&binary.parse("_0010111").unwrap()), Some(7));
+        assert_eq!(binary.preimages(&binary.parse("_01").unwrap()).unwrap(), vec![left, right]);
+
+        let longest = binary.parse(&format!("_{}1", "0".repeat(126))).unwrap();
+        assert_eq!(binary.format_fraction(&longest), format!("1/{}", u128::MAX >> 1));
+        assert_eq!(
+            binary.preimages(&longest),
+            Err("128 bits do not fit in a BinaryAngle".to_string())
+        );
+        assert!(binary.parse(&"1".repeat(128)).is_err());
+        assert!(BinaryAngle::try_new(0, 64, 1, 64).is_err());
+    }
+
+    #[test]
+    fn num_traits() {
+        let binary = BinaryAlgebra::new(2);
+
+        assert_eq!(BinaryAngle::from_f64(0.375), Some(binary.parse("011").unwrap()));
+        assert_eq!(BinaryAngle::from_f64(-0.5), Some(binary.parse("1").unwrap()));
+        assert_eq!(BinaryAngle::from_f64(f64::MIN_POSITIVE), None);
+        assert_eq!(binary.parse("_01").unwrap().to_f64(), Some(1.0 / 3.0));
+        assert_eq!(binary.parse("1_01").unwrap().to_f64(), Some(2.0 / 3.0));
+    }
+
+    #[test]
+    fn try_from_f64() {
+        assert_eq!(BinaryAngle::try_from(0.5).unwrap(), BinaryAngle::new(1, 1, 0, 0));
+        assert!(BinaryAngle::try_from(f64::MIN_POSITIVE).is_err());
+    }
+
+    #[test]
+    fn compares() {
+        let binary = BinaryAlgebra::new(2);
+        let mut angles: Vec<BinaryAngle> = ["_01", "_", "1", "_001", "_011", "0_1"]
+            .iter()
+            .map(|s| binary.parse(s).unwrap())
+            .collect();
+        angles.sort();
+        angles.dedup();
+
+        assert_eq!(
+            angles.iter().map(|angle| binary.format(angle)).collect::<Vec<String>>(),
+            vec!["_", "_001", "_01", "_011", "1_"],
+        );
+    }
+}
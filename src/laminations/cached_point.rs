@@ -0,0 +1,162 @@
+use super::{ExactUnitNumber, Powers, UnitNumber};
+use num::rational::{BigRational, Ratio};
+use std::cell::OnceCell;
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
+#[derive(Clone, Debug)]
+pub struct CachedPoint<T> {
+    point: T,
+    rational: OnceCell<(u16, Ratio<u128>)>,
+}
+
+impl<T: UnitNumber> CachedPoint<T> {
+    pub fn new(point: T) -> CachedPoint<T> {
+        CachedPoint {
+            point,
+            rational: OnceCell::new(),
+        }
+    }
+
+    pub fn point(&self) -> &T {
+        &self.point
+    }
+
+    pub fn into_inner(self) -> T {
+        self.point
+    }
+
+    fn cached_rational<F>(&self, base: u16, compute: F) -> Ratio<u128>
+    where
+        F: FnOnce() -> Ratio<u128>,
+    {
+        match self.rational.get() {
+            Some((cached_base, rational)) if *cached_base == base => *rational,
+            Some(_) => compute(),
+            None => self.rational.get_or_init(|| (base, compute())).1,
+        }
+    }
+}
+
+impl<T: ExactUnitNumber> ExactUnitNumber for CachedPoint<T> {}
+
+impl<T: UnitNumber> UnitNumber for CachedPoint<T> {
+    fn check_base(base: u16) -> Result<(), String> {
+        T::check_base(base)
+    }
+
+    fn parse_nary(base: u16, s: &str) -> Result<Self, String> {
+        T::parse_nary(base, s).map(CachedPoint::new)
+    }
+
+    fn to_rational(&self, base: u16) -> Ratio<u128> {
+        self.cached_rational(base, || self.point.to_rational(base))
+    }
+
+    fn to_rational_cached(&self, powers: &Powers) -> Ratio<u128> {
+        self.cached_rational(powers.base(), || self.point.to_rational_cached(powers))
+    }
+
+    fn to_big_rational(&self, base: u16) -> BigRational {
+        self.point.to_big_rational(base)
+    }
+
+    fn to_digits(&self, base: u16) -> (Vec<u16>, Vec<u16>) {
+        self.point.to_digits(base)
+    }
+
+    fn map(&self, base: u16) -> Self {
+        CachedPoint::new(self.point.map(base))
+    }
+
+    fn preimages(&self, base: u16) -> Result<Vec<Self>, String> {
+        Ok(self
+            .point
+            .preimages(base)?
+            .into_iter()
+            .map(CachedPoint::new)
+            .collect())
+    }
+
+    fn cmp_in_base(&self, other: &Self, base: u16) -> Ordering {
+        self.point.cmp_in_base(&other.point, base)
+    }
+
+    fn to_float(&self, base: u16) -> f64 {
+        self.point.to_float(base)
+    }
+}
+
+impl<T: PartialEq> PartialEq for CachedPoint<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.point == other.point
+    }
+}
+
+impl<T: Eq> Eq for CachedPoint<T> {}
+
+impl<T: Ord> Ord for CachedPoint<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.point.cmp(&other.point)
+    }
+}
+
+impl<T: Ord> PartialOrd for CachedPoint<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Hash> Hash for CachedPoint<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.point.hash(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{BigPoint, LaminationAlgebra, UnitFraction};
+    use super::*;
+
+    #[test]
+    fn caches_first_base() {
+        let ternary = LaminationAlgebra::<CachedPoint<UnitFraction>>::new(3);
+        let point = ternary.parse("1_021").unwrap();
+
+        assert!(point.rational.get().is_none());
+        assert_eq!(ternary.to_rational(&point), Ratio::new(11, 26));
+        assert_eq!(point.rational.get(), Some(&(3, Ratio::new(11, 26))));
+        assert_eq!(point.to_rational(3), Ratio::new(11, 26));
+        assert_eq!(point.to_rational(2), point.point().to_rational(2));
+        assert_eq!(point.rational.get(), Some(&(3, Ratio::new(11, 26))));
+    }
+
+    #[test]
+    fn delegates_to_point() {
+        let ternary = LaminationAlgebra::<CachedPoint<UnitFraction>>::new(3);
+        let point = ternary.parse("2_1").unwrap();
+
+        assert_eq!(point, ternary.parse("21_1").unwrap());
+        assert_eq!(ternary.format(&point), "2_1");
+        assert_eq!(ternary.map(&point).into_inner(), UnitFraction::parse_nary(3, "_1").unwrap());
+        assert_eq!(ternary.period(&point), None);
+        assert_eq!(
+            ternary.cmp(&point, &ternary.parse("_1").unwrap()),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn delegates_beyond_u128() {
+        let binary = LaminationAlgebra::<CachedPoint<BigPoint>>::new(2);
+        let small = binary.parse(&format!("_{}1", "0".repeat(199))).unwrap();
+        let large = binary.parse(&format!("_{}10", "0".repeat(198))).unwrap();
+
+        assert_eq!(binary.cmp(&small, &large), Ordering::Less);
+        assert_eq!(binary.map(&small), large);
+        assert!(binary.preimages(&large).unwrap().contains(&small));
+        assert_eq!(binary.to_big_rational(&small), *small.point().value());
+        assert!(large.to_float(2) > 0.0);
+        assert!(small.rational.get().is_none());
+    }
+}
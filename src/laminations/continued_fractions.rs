@@ -0,0 +1,126 @@
+use super::{UnitFraction, UnitNumber};
+use num::rational::Ratio;
+
+pub fn continued_fraction(value: &Ratio<u128>) -> Vec<u128> {
+    let (mut numerator, mut denominator) = (*value.numer(), *value.denom());
+    let mut terms = vec![];
+
+    while denominator != 0 {
+        terms.push(numerator / denominator);
+        let remainder = numerator % denominator;
+        numerator = denominator;
+        denominator = remainder;
+    }
+
+    terms
+}
+
+pub fn convergents(value: &Ratio<u128>) -> Vec<Ratio<u128>> {
+    let (mut numerator, mut previous_numerator) = (1, 0);
+    let (mut denominator, mut previous_denominator) = (0, 1);
+
+    continued_fraction(value)
+        .into_iter()
+        .map(|term| {
+            let next_numerator = term * numerator + previous_numerator;
+            let next_denominator = term * denominator + previous_denominator;
+            previous_numerator = numerator;
+            previous_denominator = denominator;
+            numerator = next_numerator;
+            denominator = next_denominator;
+            Ratio::new(numerator, denominator)
+        })
+        .collect()
+}
+
+// Returns None for zero, which has no node in the Stern-Brocot tree.
+pub fn stern_brocot_path(value: &Ratio<u128>) -> Option<String> {
+    if *value.numer() == 0 {
+        return None;
+    }
+
+    let terms = continued_fraction(value);
+    let last = terms.len() - 1;
+    let path = terms
+        .iter()
+        .enumerate()
+        .map(|(index, &term)| {
+            let step = if index % 2 == 0 { "R" } else { "L" };
+            let count = if index == last { term - 1 } else { term };
+            step.repeat(count as usize)
+        })
+        .collect();
+
+    Some(path)
+}
+
+impl UnitFraction {
+    pub fn continued_fraction(&self, base: u16) -> Vec<u128> {
+        continued_fraction(&self.to_rational(base))
+    }
+
+    pub fn convergents(&self, base: u16) -> Vec<Ratio<u128>> {
+        convergents(&self.to_rational(base))
+    }
+
+    pub fn stern_brocot_path(&self, base: u16) -> Option<String> {
+        stern_brocot_path(&self.to_rational(base))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::DefaultAlgebra;
+    use super::*;
+
+    #[test]
+    fn expands() {
+        assert_eq!(continued_fraction(&Ratio::new(0, 1)), vec![0]);
+        assert_eq!(continued_fraction(&Ratio::new(1, 2)), vec![0, 2]);
+        assert_eq!(continued_fraction(&Ratio::new(11, 26)), vec![0, 2, 2, 1, 3]);
+        assert_eq!(continued_fraction(&Ratio::new(7, 3)), vec![2, 3]);
+
+        let ternary = DefaultAlgebra::new(3);
+        assert_eq!(
+            ternary.parse("1_021").unwrap().continued_fraction(3),
+            vec![0, 2, 2, 1, 3]
+        );
+    }
+
+    #[test]
+    fn approximates() {
+        assert_eq!(
+            convergents(&Ratio::new(11, 26)),
+            vec![
+                Ratio::new(0, 1),
+                Ratio::new(1, 2),
+                Ratio::new(2, 5),
+                Ratio::new(3, 7),
+                Ratio::new(11, 26),
+            ]
+        );
+        assert_eq!(convergents(&Ratio::new(0, 1)), vec![Ratio::new(0, 1)]);
+
+        let binary = DefaultAlgebra::new(2);
+        assert_eq!(
+            binary.parse("_01").unwrap().convergents(2),
+            vec![Ratio::new(0, 1), Ratio::new(1, 3)]
+        );
+    }
+
+    #[test]
+    fn walks_stern_brocot_tree() {
+        assert_eq!(stern_brocot_path(&Ratio::new(0, 1)), None);
+        assert_eq!(stern_brocot_path(&Ratio::new(1, 1)), Some("".to_string()));
+        assert_eq!(stern_brocot_path(&Ratio::new(1, 2)), Some("L".to_string()));
+        assert_eq!(stern_brocot_path(&Ratio::new(1, 3)), Some("LL".to_string()));
+        assert_eq!(stern_brocot_path(&Ratio::new(2, 3)), Some("LR".to_string()));
+        assert_eq!(stern_brocot_path(&Ratio::new(3, 5)), Some("LRL".to_string()));
+
+        let binary = DefaultAlgebra::new(2);
+        assert_eq!(
+            binary.parse("_011").unwrap().stern_brocot_path(2),
+            Some("LLRR".to_string())
+        );
+    }
+}
@@ -0,0 +1,173 @@
+use std::fmt;
+use std::iter::FromIterator;
+use std::ops::{Deref, DerefMut};
+
+const INLINE_CAPACITY: usize = 128;
+
+// Keeping the inline variant large is the point of the buffer.
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone)]
+enum Storage {
+    Inline { digits: [u16; INLINE_CAPACITY], len: usize },
+    Heap(Vec<u16>),
+}
+
+#[derive(Clone)]
+pub struct DigitBuffer(Storage);
+
+impl DigitBuffer {
+    pub fn new() -> DigitBuffer {
+        DigitBuffer(Storage::Inline {
+            digits: [0; INLINE_CAPACITY],
+            len: 0,
+        })
+    }
+
+    pub fn zeros(len: usize) -> DigitBuffer {
+        if len > INLINE_CAPACITY {
+            return DigitBuffer(Storage::Heap(vec![0; len]));
+        }
+        DigitBuffer(Storage::Inline {
+            digits: [0; INLINE_CAPACITY],
+            len,
+        })
+    }
+
+    pub fn push(&mut self, digit: u16) {
+        match &mut self.0 {
+            Storage::Inline { digits, len } if *len < INLINE_CAPACITY => {
+                digits[*len] = digit;
+                *len += 1;
+            }
+            Storage::Inline { digits, len } => {
+                let mut spilled = digits[..*len].to_vec();
+                spilled.push(digit);
+                self.0 = Storage::Heap(spilled);
+            }
+            Storage::Heap(digits) => digits.push(digit),
+        }
+    }
+
+    pub fn pop(&mut self) -> Option<u16> {
+        match &mut self.0 {
+            Storage::Inline { len: 0, .. } => None,
+            Storage::Inline { digits, len } => {
+                *len -= 1;
+                Some(digits[*len])
+            }
+            Storage::Heap(digits) => digits.pop(),
+        }
+    }
+
+    pub fn truncate(&mut self, new_len: usize) {
+        match &mut self.0 {
+            Storage::Inline { len, .. } => *len = new_len.min(*len),
+            Storage::Heap(digits) => digits.truncate(new_len),
+        }
+    }
+
+    pub fn is_spilled(&self) -> bool {
+        matches!(self.0, Storage::Heap(_))
+    }
+
+    pub fn into_vec(self) -> Vec<u16> {
+        match self.0 {
+            Storage::Inline { digits, len } => digits[..len].to_vec(),
+            Storage::Heap(digits) => digits,
+        }
+    }
+}
+
+impl Default for DigitBuffer {
+    fn default() -> Self {
+        DigitBuffer::new()
+    }
+}
+
+impl Deref for DigitBuffer {
+    type Target = [u16];
+
+    fn deref(&self) -> &[u16] {
+        match &self.0 {
+            Storage::Inline { digits, len } => &digits[..*len],
+            Storage::Heap(digits) => digits,
+        }
+    }
+}
+
+impl DerefMut for DigitBuffer {
+    fn deref_mut(&mut self) -> &mut [u16] {
+        match &mut self.0 {
+            Storage::Inline { digits, len } => &mut digits[..*len],
+            Storage::Heap(digits) => digits,
+        }
+    }
+}
+
+impl fmt::Debug for DigitBuffer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl PartialEq for DigitBuffer {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl Eq for DigitBuffer {}
+
+impl FromIterator<u16> for DigitBuffer {
+    fn from_iter<I: IntoIterator<Item = u16>>(iter: I) -> Self {
+        let mut buffer = DigitBuffer::new();
+        for digit in iter {
+            buffer.push(digit);
+        }
+        buffer
+    }
+}
+
+impl From<DigitBuffer> for Vec<u16> {
+    fn from(buffer: DigitBuffer) -> Self {
+        buffer.into_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spills_past_inline_capacity() {
+        let mut buffer: DigitBuffer = (0..INLINE_CAPACITY).map(|digit| digit as u16).collect();
+        assert!(!buffer.is_spilled());
+        assert_eq!(buffer.len(), INLINE_CAPACITY);
+
+        buffer.push(7);
+        assert!(buffer.is_spilled());
+        assert_eq!(buffer.len(), INLINE_CAPACITY + 1);
+        assert_eq!(buffer[INLINE_CAPACITY], 7);
+        assert_eq!(buffer[3], 3);
+
+        assert_eq!(buffer.pop(), Some(7));
+        buffer.truncate(2);
+        assert_eq!(&*buffer, &[0, 1]);
+
+        let digits: Vec<u16> = buffer.into();
+        assert_eq!(digits, vec![0, 1]);
+    }
+
+    #[test]
+    fn behaves_like_a_slice() {
+        let mut buffer = DigitBuffer::zeros(3);
+        buffer[1] = 2;
+        buffer.reverse();
+
+        assert_eq!(&*buffer, &[0, 2, 0]);
+        assert_eq!(buffer, vec![0, 2, 0].into_iter().collect());
+        assert_eq!(format!("{:?}", buffer), "[0, 2, 0]");
+        assert!(DigitBuffer::zeros(200).is_spilled());
+        assert_eq!(DigitBuffer::new().pop(), None);
+    }
+}
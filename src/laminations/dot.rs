@@ -0,0 +1,100 @@
+use super::{ExactUnitNumber, LaminationAlgebra, Leaf};
+use std::fmt::Write;
+
+impl<T: ExactUnitNumber> LaminationAlgebra<T> {
+    pub fn orbit_graph_dot(&self, points: &[T]) -> String {
+        let mut nodes: Vec<T> = vec![];
+        for point in points {
+            for orbit_point in self.orbit(point) {
+                if !nodes.contains(&orbit_point) {
+                    nodes.push(orbit_point);
+                }
+            }
+        }
+
+        let mut dot = String::from("digraph orbits {\n");
+        for (index, node) in nodes.iter().enumerate() {
+            writeln!(dot, "    n{} [label=\"{}\"];", index, self.format(node)).unwrap();
+        }
+        for (index, node) in nodes.iter().enumerate() {
+            let image = self.map(node);
+            let image_index = nodes.iter().position(|other| *other == image).unwrap();
+            writeln!(dot, "    n{} -> n{};", index, image_index).unwrap();
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    // Leaves as nodes, joined when they cross. Leaves that cross nothing are kept so node numbers
+    // match the slice.
+    pub fn crossing_graph_dot(&self, leaves: &[Leaf<T>]) -> String {
+        let mut dot = String::from("graph crossings {\n");
+        for (index, (a, b)) in leaves.iter().enumerate() {
+            writeln!(dot, "    n{} [label=\"{} {}\"];", index, self.format(a), self.format(b))
+                .unwrap();
+        }
+        for (i, j) in self.crossings(leaves) {
+            writeln!(dot, "    n{} -- n{};", i, j).unwrap();
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::DefaultAlgebra;
+    use super::super::test_support::pairs;
+
+    #[test]
+    fn orbit_graph() {
+        let binary = DefaultAlgebra::new(2);
+        let points = vec![
+            binary.parse("0_01").unwrap(),
+            binary.parse("_01").unwrap(),
+            binary.parse("_").unwrap(),
+        ];
+
+        assert_eq!(
+            binary.orbit_graph_dot(&points),
+            "digraph orbits {
+    n0 [label=\"0_01\"];
+    n1 [label=\"_01\"];
+    n2 [label=\"_10\"];
+    n3 [label=\"_\"];
+    n0 -> n1;
+    n1 -> n2;
+    n2 -> n1;
+    n3 -> n3;
+}
+"
+        );
+    }
+
+    #[test]
+    fn crossing_graph() {
+        let binary = DefaultAlgebra::new(2);
+        let leaves = pairs(
+            &binary,
+            &[("_01", "_10"), ("0_01", "1_10"), ("_0", "1"), ("_001", "_010")],
+        );
+
+        assert_eq!(binary.crossings(&leaves), vec![(0, 2), (1, 2), (1, 3)]);
+        let reversed: Vec<_> = leaves.iter().map(|(a, b)| (b.clone(), a.clone())).collect();
+        assert_eq!(binary.crossings(&reversed), vec![(0, 2), (1, 2), (1, 3)]);
+        assert!(binary.crossing_graph_dot(&reversed).contains("    n1 -- n3;\n"));
+        assert_eq!(
+            binary.crossing_graph_dot(&leaves),
+            "graph crossings {
+    n0 [label=\"_01 _10\"];
+    n1 [label=\"0_01 1_10\"];
+    n2 [label=\"_ 1_\"];
+    n3 [label=\"_001 _010\"];
+    n0 -- n2;
+    n1 -- n2;
+    n1 -- n3;
+}
+"
+        );
+    }
+}
@@ -0,0 +1,165 @@
+use super::{digits_to_string, DefaultAlgebra, UnitFraction, UnitNumber};
+use num::rational::Ratio;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Exercise {
+    ToFraction { base: u16, expansion: String, answer: Ratio<u128> },
+    Iterates { base: u16, start: String, steps: usize, answer: Vec<String> },
+}
+
+impl Exercise {
+    pub fn prompt(&self) -> String {
+        match self {
+            Exercise::ToFraction { base, expansion, .. } => format!(
+                "Convert the base-{} expansion {} to a fraction p/q.",
+                base, expansion
+            ),
+            Exercise::Iterates { base, start, steps, .. } => format!(
+                "Compute the first {} iterates of {} under multiplication by {}.",
+                steps, start, base
+            ),
+        }
+    }
+
+    // Fractions may be given unreduced and iterates in any digit form that parses to the same
+    // point.
+    pub fn check(&self, response: &str) -> bool {
+        match self {
+            Exercise::ToFraction { answer, .. } => {
+                let mut parts = response.trim().splitn(2, '/');
+                let numerator = parts.next().and_then(|p| p.trim().parse::<u128>().ok());
+                let denominator = parts.next().and_then(|q| q.trim().parse::<u128>().ok());
+                match (numerator, denominator) {
+                    (Some(p), Some(q)) if q != 0 => Ratio::new(p, q) == *answer,
+                    _ => false,
+                }
+            }
+            Exercise::Iterates { base, answer, .. } => {
+                let algebra = DefaultAlgebra::new(*base);
+                let given: Vec<&str> = response.split_whitespace().collect();
+                given.len() == answer.len()
+                    && given.iter().zip(answer).all(|(given, expected)| {
+                        algebra.parse(given).ok() == algebra.parse(expected).ok()
+                    })
+            }
+        }
+    }
+}
+
+impl DefaultAlgebra {
+    // Randomness comes from the caller, e.g. `|| rng.gen()` with any generator.
+    pub fn random_exercise<R: FnMut() -> u64>(&self, random: &mut R) -> Exercise {
+        let mut below = |bound: u64| random() % bound;
+        let base = self.base;
+
+        let exact_len = below(3) as usize;
+        let repeating_len = 1 + below(3) as usize;
+        let exact: Vec<u16> = (0..exact_len).map(|_| below(base as u64) as u16).collect();
+        // A repeating block of all base - 1 digits would make the expansion equal 1, whose stored
+        // answer 0 a correct "1/1" response would not match.
+        let repeating: Vec<u16> = loop {
+            let repeating: Vec<u16> =
+                (0..repeating_len).map(|_| below(base as u64) as u16).collect();
+            if repeating.iter().any(|&digit| digit != base - 1) {
+                break repeating;
+            }
+        };
+        let point = UnitFraction::from_digits(base, &exact, &repeating);
+
+        if below(2) == 0 {
+            let expansion = format!(
+                "{}_{}",
+                digits_to_string(base, &exact),
+                digits_to_string(base, &repeating)
+            );
+            Exercise::ToFraction { base, expansion, answer: point.to_rational(base) }
+        } else {
+            let steps = 3 + below(3) as usize;
+            let mut iterates = vec![];
+            let mut current = point.clone();
+            for _ in 0..steps {
+                current = self.map(&current);
+                iterates.push(self.format(&current));
+            }
+            Exercise::Iterates { base, start: self.format(&point), steps, answer: iterates }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn xorshift(mut state: u64) -> impl FnMut() -> u64 {
+        move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        }
+    }
+
+    #[test]
+    fn generates_checkable_exercises() {
+        let ternary = DefaultAlgebra::new(3);
+        let mut random = xorshift(0x2545_f491_4f6c_dd1d);
+
+        for _ in 0..50 {
+            let exercise = ternary.random_exercise(&mut random);
+            assert!(!exercise.prompt().is_empty());
+            match &exercise {
+                Exercise::ToFraction { answer, .. } => {
+                    let unreduced = format!("{}/{}", answer.numer() * 2, answer.denom() * 2);
+                    assert!(exercise.check(&unreduced));
+                    assert!(!exercise.check(&format!("{}/{}", answer.numer() + 1, answer.denom())));
+                    assert!(!exercise.check("1/0"));
+                }
+                Exercise::Iterates { answer, .. } => {
+                    assert!(exercise.check(&answer.join(" ")));
+                    assert!(!exercise.check(&answer[1..].join(" ")));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn redraws_expansions_of_one() {
+        let ternary = DefaultAlgebra::new(3);
+        // No exact digits, one repeating digit 2, then 1 after the redraw, then a fraction.
+        let mut draws = vec![0, 0, 2, 1, 0].into_iter();
+        let mut random = || draws.next().unwrap();
+
+        let exercise = ternary.random_exercise(&mut random);
+        assert_eq!(
+            exercise,
+            Exercise::ToFraction {
+                base: 3,
+                expansion: "_1".to_string(),
+                answer: Ratio::new(1, 2),
+            }
+        );
+    }
+
+    #[test]
+    fn prompts() {
+        let exercise = Exercise::ToFraction {
+            base: 3,
+            expansion: "1_021".to_string(),
+            answer: Ratio::new(11, 26),
+        };
+        assert_eq!(
+            exercise.prompt(),
+            "Convert the base-3 expansion 1_021 to a fraction p/q."
+        );
+        assert!(exercise.check(" 22 / 52 "));
+
+        let exercise = Exercise::Iterates {
+            base: 2,
+            start: "0_001".to_string(),
+            steps: 2,
+            answer: vec!["_001".to_string(), "_010".to_string()],
+        };
+        assert!(exercise.check("_001 0_100"));
+        assert!(!exercise.check("_001"));
+    }
+}
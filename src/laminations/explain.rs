@@ -0,0 +1,92 @@
+use super::{parse_digit_parts, value_from_digits, DefaultAlgebra, UnitFraction, UnitNumber};
+use num::rational::Ratio;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseStep {
+    Digits { exact: Vec<u16>, repeating: Vec<u16> },
+    Values { exact: u128, repeating: u128 },
+    Series { numerator: u128, denominator: u128 },
+    Reduced(Ratio<u128>),
+    Canonical { exact: Vec<u16>, repeating: Vec<u16> },
+}
+
+impl DefaultAlgebra {
+    // Parses like `parse`, recording the digits as written, their values, the geometric series
+    // exact / b^e + repeating / (b^e (b^r - 1)) as one unreduced fraction, the reduced fraction
+    // and the canonical digits.
+    pub fn parse_explained(&self, s: &str) -> Result<(UnitFraction, Vec<ParseStep>), String> {
+        let s = self.normalize_input(s);
+        let (exact, repeating) = parse_digit_parts(self.base, &s)?;
+        let too_long = || format!("`{}` has too many digits to explain", s);
+
+        let exact_value = value_from_digits(self.base, &exact).ok_or_else(too_long)?;
+        let repeating_value = value_from_digits(self.base, &repeating).ok_or_else(too_long)?;
+
+        let base = self.base as u128;
+        let exact_scale = base.checked_pow(exact.len() as u32).ok_or_else(too_long)?;
+        let repeating_scale = match repeating.len() {
+            0 => 1,
+            len => base.checked_pow(len as u32).ok_or_else(too_long)? - 1,
+        };
+        let numerator = exact_value
+            .checked_mul(repeating_scale)
+            .and_then(|scaled| scaled.checked_add(repeating_value))
+            .ok_or_else(too_long)?;
+        let denominator = exact_scale.checked_mul(repeating_scale).ok_or_else(too_long)?;
+
+        // The series can fit while the canonical point still needs more digits than u128 holds.
+        let point = UnitFraction::try_from_digits(self.base, &exact, &repeating)
+            .map_err(|error| format!("{}: {}", s, error))?;
+        let (canonical_exact, canonical_repeating) = point.to_digits(self.base);
+        let steps = vec![
+            ParseStep::Digits { exact: exact.to_vec(), repeating: repeating.to_vec() },
+            ParseStep::Values { exact: exact_value, repeating: repeating_value },
+            ParseStep::Series { numerator, denominator },
+            ParseStep::Reduced(self.to_rational(&point)),
+            ParseStep::Canonical { exact: canonical_exact, repeating: canonical_repeating },
+        ];
+        Ok((point, steps))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn explains_parsing() {
+        let ternary = DefaultAlgebra::new(3);
+        let (point, steps) = ternary.parse_explained("10_210").unwrap();
+
+        assert_eq!(point, ternary.parse("_102").unwrap());
+        assert_eq!(
+            steps,
+            vec![
+                ParseStep::Digits { exact: vec![1, 0], repeating: vec![2, 1, 0] },
+                ParseStep::Values { exact: 3, repeating: 21 },
+                ParseStep::Series { numerator: 99, denominator: 234 },
+                ParseStep::Reduced(Ratio::new(11, 26)),
+                ParseStep::Canonical { exact: vec![], repeating: vec![1, 0, 2] },
+            ]
+        );
+
+        let (_, steps) = ternary.parse_explained("_").unwrap();
+        assert_eq!(steps[2], ParseStep::Series { numerator: 0, denominator: 1 });
+
+        assert_eq!(
+            ternary.parse_explained("1_2_1"),
+            Err("`1_2_1` contains more than one underscore".to_string())
+        );
+        let binary = DefaultAlgebra::new(2);
+        let long = format!("_{}", "01".repeat(70));
+        assert_eq!(
+            binary.parse_explained(&long),
+            Err(format!("`{}` has too many digits to explain", long))
+        );
+        let wide = format!("{}1_1{}", "0".repeat(63), "0".repeat(63));
+        assert_eq!(
+            binary.parse_explained(&wide),
+            Err(format!("{}: 128 digits in base 2 do not fit in u128", wide))
+        );
+    }
+}
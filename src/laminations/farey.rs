@@ -0,0 +1,76 @@
+use super::{convergents, UnitFraction, UnitNumber};
+use num::rational::Ratio;
+
+pub fn mediant(a: &Ratio<u128>, b: &Ratio<u128>) -> Ratio<u128> {
+    Ratio::new(a.numer() + b.numer(), a.denom() + b.denom())
+}
+
+// The neighbors of p/q in the Farey sequence of order q, or None for 0/1 and 1/1.
+pub fn farey_neighbors(value: &Ratio<u128>) -> Option<(Ratio<u128>, Ratio<u128>)> {
+    let (p, q) = (*value.numer(), *value.denom());
+    if q == 1 {
+        return None;
+    }
+
+    let approximations = convergents(value);
+    let previous = approximations[approximations.len() - 2];
+    let (h, k) = (*previous.numer(), *previous.denom());
+    let other = Ratio::new(p - h, q - k);
+
+    if p * k > q * h {
+        Some((previous, other))
+    } else {
+        Some((other, previous))
+    }
+}
+
+impl UnitFraction {
+    pub fn farey_neighbors(&self, base: u16) -> Option<(Ratio<u128>, Ratio<u128>)> {
+        farey_neighbors(&self.to_rational(base))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::DefaultAlgebra;
+    use super::*;
+
+    #[test]
+    fn takes_mediants() {
+        assert_eq!(mediant(&Ratio::new(0, 1), &Ratio::new(1, 1)), Ratio::new(1, 2));
+        assert_eq!(mediant(&Ratio::new(1, 3), &Ratio::new(1, 2)), Ratio::new(2, 5));
+    }
+
+    #[test]
+    fn finds_neighbors() {
+        assert_eq!(farey_neighbors(&Ratio::new(0, 1)), None);
+        assert_eq!(
+            farey_neighbors(&Ratio::new(1, 2)),
+            Some((Ratio::new(0, 1), Ratio::new(1, 1)))
+        );
+        assert_eq!(
+            farey_neighbors(&Ratio::new(2, 5)),
+            Some((Ratio::new(1, 3), Ratio::new(1, 2)))
+        );
+        assert_eq!(
+            farey_neighbors(&Ratio::new(3, 7)),
+            Some((Ratio::new(2, 5), Ratio::new(1, 2)))
+        );
+
+        for q in 2..30u128 {
+            for p in 1..q {
+                let value = Ratio::new(p, q);
+                if let Some((left, right)) = farey_neighbors(&value) {
+                    assert!(left < value && value < right);
+                    assert_eq!(mediant(&left, &right), value);
+                }
+            }
+        }
+
+        let binary = DefaultAlgebra::new(2);
+        assert_eq!(
+            binary.parse("_001").unwrap().farey_neighbors(2),
+            Some((Ratio::new(0, 1), Ratio::new(1, 6)))
+        );
+    }
+}
@@ -0,0 +1,297 @@
+use super::{parse_digit_parts, Leaf, UnitNumber};
+use num::rational::Ratio;
+use std::cmp::Ordering;
+
+#[derive(Clone, Copy, Debug)]
+pub struct FloatPoint(f64);
+
+impl FloatPoint {
+    pub const TOLERANCE: f64 = 1e-12;
+
+    // rem_euclid rounds tiny negative values up to exactly 1, which is 0 on the circle.
+    pub fn new(value: f64) -> FloatPoint {
+        let value = value.rem_euclid(1.0);
+        if value == 1.0 {
+            return FloatPoint(0.0);
+        }
+        FloatPoint(value)
+    }
+
+    pub fn value(&self) -> f64 {
+        self.0
+    }
+
+    // Equality and ordering are exact; comparisons that should forgive rounding go through
+    // approx_eq or dedup_within instead, since a tolerance is not transitive.
+    pub fn approx_eq(&self, other: &FloatPoint, tolerance: f64) -> bool {
+        self.distance(other) <= tolerance
+    }
+
+    pub fn dedup_within(points: &[FloatPoint], tolerance: f64) -> Vec<FloatPoint> {
+        let mut sorted = points.to_vec();
+        sorted.sort();
+
+        let mut representatives: Vec<FloatPoint> = vec![];
+        let mut previous: Option<FloatPoint> = None;
+        for point in sorted {
+            match previous {
+                Some(last) if point.0 - last.0 <= tolerance => {}
+                _ => representatives.push(point),
+            }
+            previous = Some(point);
+        }
+
+        if let (Some(first), Some(last)) = (representatives.first(), previous) {
+            if representatives.len() > 1 && first.0 + 1.0 - last.0 <= tolerance {
+                representatives.pop();
+            }
+        }
+
+        representatives
+    }
+
+    // Snaps every endpoint to the nearest representative of dedup_within over all endpoints, then
+    // drops leaves that coincide after snapping, in either orientation.
+    pub fn dedup_leaves_within(
+        leaves: &[Leaf<FloatPoint>],
+        tolerance: f64,
+    ) -> Vec<Leaf<FloatPoint>> {
+        let endpoints: Vec<FloatPoint> = leaves.iter().flat_map(|&(a, b)| vec![a, b]).collect();
+        let representatives = FloatPoint::dedup_within(&endpoints, tolerance);
+        let snap = |point: FloatPoint| -> FloatPoint {
+            *representatives
+                .iter()
+                .min_by(|x, y| point.distance(x).total_cmp(&point.distance(y)))
+                .unwrap()
+        };
+
+        let mut merged: Vec<Leaf<FloatPoint>> = vec![];
+        for &(a, b) in leaves {
+            let (a, b) = (snap(a), snap(b));
+            let leaf = if a <= b { (a, b) } else { (b, a) };
+            if !merged.contains(&leaf) {
+                merged.push(leaf);
+            }
+        }
+        merged
+    }
+
+    fn distance(&self, other: &FloatPoint) -> f64 {
+        let difference = (self.0 - other.0).abs();
+        difference.min(1.0 - difference)
+    }
+}
+
+impl UnitNumber for FloatPoint {
+    fn parse_nary(base: u16, s: &str) -> Result<Self, String> {
+        let (exact_digits, repeating_digits) = parse_digit_parts(base, s)?;
+        let digit_count = exact_digits.len() + repeating_digits.len();
+        let base = base as f64;
+
+        let exact_scale = base.powi(exact_digits.len() as i32);
+        let exact_value = float_from_digits(base, &exact_digits) / exact_scale;
+        let repeating_scale = base.powi(repeating_digits.len() as i32) - 1.0;
+        let repeating_value = if repeating_digits.is_empty() {
+            0.0
+        } else {
+            float_from_digits(base, &repeating_digits) / repeating_scale / exact_scale
+        };
+
+        let value = exact_value + repeating_value;
+        if !value.is_finite() {
+            return Err(format!("{}: {} digits in base {} overflow an f64", s, digit_count, base));
+        }
+        Ok(FloatPoint::new(value))
+    }
+
+    fn to_rational(&self, _base: u16) -> Ratio<u128> {
+        let denominator = 1u128 << 64;
+        let numerator = (self.0 * denominator as f64).round() as u128;
+        Ratio::new(numerator, denominator)
+    }
+
+    fn to_digits(&self, base: u16) -> (Vec<u16>, Vec<u16>) {
+        let digit_count = (f64::MANTISSA_DIGITS as f64 / (base as f64).log2()).ceil() as usize;
+        let mut digits = Vec::with_capacity(digit_count);
+        let mut remaining = self.0;
+        for _ in 0..digit_count {
+            remaining *= base as f64;
+            let digit = remaining.floor();
+            digits.push(digit as u16);
+            remaining -= digit;
+        }
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+        (digits, vec![])
+    }
+
+    fn to_float(&self, _base: u16) -> f64 {
+        self.0
+    }
+
+    fn map(&self, base: u16) -> Self {
+        FloatPoint::new(self.0 * base as f64)
+    }
+
+    fn preimages(&self, base: u16) -> Result<Vec<Self>, String> {
+        Ok((0..base)
+            .map(|digit| FloatPoint::new((self.0 + digit as f64) / base as f64))
+            .collect())
+    }
+
+    fn cmp_in_base(&self, other: &Self, _base: u16) -> Ordering {
+        self.cmp(other)
+    }
+}
+
+fn float_from_digits(base: f64, digits: &[u16]) -> f64 {
+    digits
+        .iter()
+        .fold(0.0, |sum, &digit| sum * base + digit as f64)
+}
+
+impl PartialEq for FloatPoint {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for FloatPoint {}
+
+impl Ord for FloatPoint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl PartialOrd for FloatPoint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl_primitive_conversions!(
+    FloatPoint,
+    zero: FloatPoint::new(0.0),
+    to_f64: |x| Some(x.0),
+    from_f64: |n| if n.is_finite() { Some(FloatPoint::new(n)) } else { None },
+);
+
+#[cfg(test)]
+mod tests {
+    use super::super::LaminationAlgebra;
+    use super::*;
+    use num::{FromPrimitive, ToPrimitive};
+
+    #[test]
+    fn parse_float() {
+        let ternary = LaminationAlgebra::<FloatPoint>::new(3);
+
+        assert_eq!(ternary.parse("1_021").unwrap().value(), 11.0 / 26.0);
+        assert_eq!(ternary.parse("_1").unwrap(), FloatPoint::new(0.5));
+        assert_eq!(ternary.parse("_2").unwrap(), FloatPoint::new(0.0));
+        assert!(ternary
+            .parse("1_2")
+            .unwrap()
+            .approx_eq(&ternary.parse("2_").unwrap(), FloatPoint::TOLERANCE));
+        assert!(ternary.parse("1_1_1").is_err());
+
+        let nonary = LaminationAlgebra::<FloatPoint>::new(9);
+        assert!(nonary.parse(&format!("_{}", "12345678".repeat(50))).is_err());
+    }
+
+    #[test]
+    fn compares_exactly() {
+        assert_ne!(FloatPoint::new(0.25), FloatPoint::new(0.25 + 1e-14));
+        assert!(FloatPoint::new(0.25) < FloatPoint::new(0.25 + 1e-14));
+        assert!(FloatPoint::new(0.25) < FloatPoint::new(0.5));
+        assert!(FloatPoint::new(1.0 - 1e-14) > FloatPoint::new(0.0));
+        assert_eq!(FloatPoint::new(-1e-20), FloatPoint::new(0.0));
+        assert_eq!(FloatPoint::new(1.0), FloatPoint::new(0.0));
+    }
+
+    #[test]
+    fn compares_within_tolerance() {
+        let tolerance = FloatPoint::TOLERANCE;
+        assert!(FloatPoint::new(0.25).approx_eq(&FloatPoint::new(0.25 + 1e-14), tolerance));
+        assert!(FloatPoint::new(0.99).approx_eq(&FloatPoint::new(0.01), 0.03));
+        assert!(FloatPoint::new(1.0 - 1e-14).approx_eq(&FloatPoint::new(1e-14), tolerance));
+        assert!(!FloatPoint::new(0.25).approx_eq(&FloatPoint::new(0.25 + 1e-9), tolerance));
+    }
+
+    #[test]
+    fn converts() {
+        let binary = LaminationAlgebra::<FloatPoint>::new(2);
+        let point = binary.parse("011").unwrap();
+
+        assert_eq!(point.to_rational(2), Ratio::new(3, 8));
+        assert_eq!(binary.format_digits(&point), (vec![0, 1, 1], vec![]));
+        assert_eq!(binary.format(&point), "011_");
+    }
+
+    #[test]
+    fn dedups_within_tolerance() {
+        let points: Vec<FloatPoint> = [0.5, 0.2, 0.50004, 0.99997, 0.25, 0.50008, 0.00002]
+            .iter()
+            .map(|&value| FloatPoint::new(value))
+            .collect();
+
+        let values = |points: Vec<FloatPoint>| -> Vec<f64> {
+            points.iter().map(|point| point.value()).collect()
+        };
+        assert_eq!(
+            values(FloatPoint::dedup_within(&points, 1e-4)),
+            vec![0.00002, 0.2, 0.25, 0.5]
+        );
+        assert_eq!(values(FloatPoint::dedup_within(&points, 0.06)), vec![0.00002, 0.2, 0.5]);
+        assert_eq!(values(FloatPoint::dedup_within(&points, 1e-6)).len(), 7);
+        assert_eq!(values(FloatPoint::dedup_within(&points, 1.0)), vec![0.00002]);
+        assert!(FloatPoint::dedup_within(&[], 0.1).is_empty());
+    }
+
+    #[test]
+    fn dedups_leaves_within_tolerance() {
+        let leaf = |a: f64, b: f64| (FloatPoint::new(a), FloatPoint::new(b));
+        let leaves = vec![
+            leaf(0.2, 0.5),
+            leaf(0.50004, 0.20003),
+            leaf(0.99998, 0.5),
+            leaf(0.00001, 0.50002),
+            leaf(0.25, 0.75),
+        ];
+
+        assert_eq!(
+            FloatPoint::dedup_leaves_within(&leaves, 1e-4),
+            vec![leaf(0.2, 0.5), leaf(0.00001, 0.5), leaf(0.25, 0.75)]
+        );
+        assert_eq!(FloatPoint::dedup_leaves_within(&leaves, 1e-6).len(), 5);
+        assert!(FloatPoint::dedup_leaves_within(&[], 0.1).is_empty());
+    }
+
+    #[test]
+    fn num_traits() {
+        assert_eq!(FloatPoint::from_f64(1.25).unwrap().to_f64(), Some(0.25));
+        assert_eq!(FloatPoint::from_f64(-0.25).unwrap().to_f64(), Some(0.75));
+        assert_eq!(FloatPoint::from_f64(f64::NAN), None);
+        assert_eq!(FloatPoint::from_u64(3).unwrap().to_u64(), Some(0));
+    }
+
+    #[test]
+    fn dynamics() {
+        let ternary = LaminationAlgebra::<FloatPoint>::new(3);
+        let point = ternary.parse("_01").unwrap();
+
+        let close = |a: &FloatPoint, b: &str| a.approx_eq(&ternary.parse(b).unwrap(), 1e-12);
+
+        assert!(close(&ternary.map(&point), "_10"));
+        assert!(close(&ternary.map(&ternary.map(&point)), "_01"));
+        assert_eq!(ternary.orbit_bounded(&FloatPoint::from_f64(0.1234567).unwrap(), 1000), None);
+
+        let preimages = ternary.preimages(&point).unwrap();
+        assert_eq!(preimages.len(), 3);
+        for (preimage, expected) in preimages.iter().zip(&["0_01", "1_01", "2_01"]) {
+            assert!(close(preimage, expected));
+        }
+    }
+}
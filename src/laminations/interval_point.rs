@@ -0,0 +1,320 @@
+use super::{parse_digit_parts, UnitNumber};
+use num::rational::Ratio;
+use num::Float;
+use std::cmp::Ordering;
+
+#[derive(Clone, Copy, Debug)]
+pub struct IntervalPoint {
+    lower: f64,
+    upper: f64,
+}
+
+impl IntervalPoint {
+    pub fn new(lower: f64, upper: f64) -> IntervalPoint {
+        assert!(
+            lower.is_finite() && upper.is_finite() && lower <= upper,
+            "[{}, {}] is not a finite interval",
+            lower,
+            upper
+        );
+        let shift = lower.floor();
+        IntervalPoint {
+            lower: lower - shift,
+            upper: upper - shift,
+        }
+    }
+
+    pub fn around(value: f64, error: f64) -> IntervalPoint {
+        IntervalPoint::new((value - error).next_down(), (value + error).next_up())
+    }
+
+    pub fn lower(&self) -> f64 {
+        self.lower
+    }
+
+    pub fn upper(&self) -> f64 {
+        self.upper
+    }
+
+    pub fn width(&self) -> f64 {
+        self.upper - self.lower
+    }
+
+    pub fn midpoint(&self) -> f64 {
+        (self.lower + self.upper) / 2.0
+    }
+
+    // Overlap is not transitive, so it lives here rather than in Eq, which compares the bounds.
+    pub fn provably_distinct(&self, other: &IntervalPoint) -> bool {
+        [-1.0, 0.0, 1.0].iter().all(|shift| {
+            self.upper < other.lower + shift || other.upper + shift < self.lower
+        })
+    }
+
+    pub fn may_equal(&self, other: &IntervalPoint) -> bool {
+        !self.provably_distinct(other)
+    }
+
+    fn add(self, other: IntervalPoint) -> IntervalPoint {
+        IntervalPoint {
+            lower: (self.lower + other.lower).next_down(),
+            upper: (self.upper + other.upper).next_up(),
+        }
+    }
+
+    fn scale(self, factor: f64, offset: f64) -> IntervalPoint {
+        IntervalPoint {
+            lower: (self.lower * factor + offset).next_down().next_down(),
+            upper: (self.upper * factor + offset).next_up().next_up(),
+        }
+    }
+
+    fn divide(self, divisor: IntervalPoint) -> IntervalPoint {
+        IntervalPoint {
+            lower: (self.lower / divisor.upper).next_down(),
+            upper: (self.upper / divisor.lower).next_up(),
+        }
+    }
+}
+
+impl UnitNumber for IntervalPoint {
+    fn parse_nary(base: u16, s: &str) -> Result<Self, String> {
+        let (exact_digits, repeating_digits) = parse_digit_parts(base, s)?;
+        let digit_count = exact_digits.len() + repeating_digits.len();
+        let finite = |value: IntervalPoint| {
+            if value.lower.is_finite() && value.upper.is_finite() {
+                Ok(IntervalPoint::new(value.lower, value.upper))
+            } else {
+                Err(format!("{}: {} digits in base {} overflow an f64", s, digit_count, base))
+            }
+        };
+        let base = base as f64;
+        let exact = |value: f64| IntervalPoint {
+            lower: value,
+            upper: value,
+        };
+
+        let exact_scale = interval_from_digits(base, &vec![0; exact_digits.len()], 1.0);
+        let exact_value = interval_from_digits(base, &exact_digits, 0.0).divide(exact_scale);
+        if repeating_digits.is_empty() {
+            return finite(exact_value);
+        }
+
+        let repeating_scale = interval_from_digits(base, &vec![0; repeating_digits.len()], 1.0)
+            .add(exact(-1.0));
+        let repeating_value = interval_from_digits(base, &repeating_digits, 0.0)
+            .divide(repeating_scale)
+            .divide(exact_scale);
+
+        finite(exact_value.add(repeating_value))
+    }
+
+    fn to_rational(&self, _base: u16) -> Ratio<u128> {
+        dyadic_ratio(self.midpoint())
+    }
+
+    fn to_digits(&self, base: u16) -> (Vec<u16>, Vec<u16>) {
+        let mut digits = vec![];
+        let (mut lower, mut upper) = (self.lower, self.upper);
+        if upper >= 1.0 {
+            return (digits, vec![]);
+        }
+
+        while lower != upper && digits.len() < f64::MANTISSA_DIGITS as usize {
+            lower *= base as f64;
+            upper *= base as f64;
+            if lower.floor() != upper.floor() {
+                break;
+            }
+            digits.push(lower.floor() as u16);
+            lower -= lower.floor();
+            upper -= upper.floor();
+        }
+
+        (digits, vec![])
+    }
+
+    fn to_float(&self, _base: u16) -> f64 {
+        self.midpoint()
+    }
+
+    fn map(&self, base: u16) -> Self {
+        let mapped = self.scale(base as f64, 0.0);
+        IntervalPoint::new(mapped.lower, mapped.upper)
+    }
+
+    fn preimages(&self, base: u16) -> Result<Vec<Self>, String> {
+        let base = IntervalPoint {
+            lower: base as f64,
+            upper: base as f64,
+        };
+        Ok((0..base.lower as u16)
+            .map(|digit| {
+                let preimage = self.scale(1.0, digit as f64).divide(base);
+                IntervalPoint::new(preimage.lower, preimage.upper)
+            })
+            .collect())
+    }
+
+    fn cmp_in_base(&self, other: &Self, _base: u16) -> Ordering {
+        self.cmp(other)
+    }
+}
+
+fn interval_from_digits(base: f64, digits: &[u16], leading: f64) -> IntervalPoint {
+    digits.iter().fold(
+        IntervalPoint {
+            lower: leading,
+            upper: leading,
+        },
+        |sum, &digit| sum.scale(base, digit as f64),
+    )
+}
+
+// Exact for values on a 2^-127 grid; finer bits round to nearest, since the denominator has to
+// fit in a u128.
+fn dyadic_ratio(value: f64) -> Ratio<u128> {
+    let (mantissa, exponent, _) = value.integer_decode();
+    let (mantissa, shift) = (mantissa as u128, -exponent as u32);
+    if shift <= 127 {
+        return Ratio::new(mantissa, 1 << shift);
+    }
+    let excess = shift - 127;
+    let numerator = if excess > f64::MANTISSA_DIGITS {
+        0
+    } else {
+        (mantissa + (1 << (excess - 1))) >> excess
+    };
+    Ratio::new(numerator, 1 << 127)
+}
+
+impl PartialEq for IntervalPoint {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for IntervalPoint {}
+
+impl Ord for IntervalPoint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.lower
+            .total_cmp(&other.lower)
+            .then_with(|| self.upper.total_cmp(&other.upper))
+    }
+}
+
+impl PartialOrd for IntervalPoint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl_primitive_conversions!(
+    IntervalPoint,
+    zero: IntervalPoint::new(0.0, 0.0),
+    to_f64: |x| Some(x.midpoint()),
+    from_f64: |n| if n.is_finite() { Some(IntervalPoint::new(n, n)) } else { None },
+);
+
+#[cfg(test)]
+mod tests {
+    use super::super::LaminationAlgebra;
+    use super::*;
+    use num::{FromPrimitive, ToPrimitive};
+
+    #[test]
+    fn parse_encloses_value() {
+        let ternary = LaminationAlgebra::<IntervalPoint>::new(3);
+
+        let point = ternary.parse("1_021").unwrap();
+        assert!(point.lower() <= 11.0 / 26.0 && 11.0 / 26.0 <= point.upper());
+        assert!(point.width() < 1e-14);
+
+        let exact = ternary.parse("12").unwrap();
+        assert!(exact.lower() <= 5.0 / 9.0 && 5.0 / 9.0 <= exact.upper());
+        assert!(ternary.parse("1_1_1").is_err());
+        assert_eq!(
+            ternary.parse(&"1".repeat(700)),
+            Err(format!("{}: 700 digits in base 3 overflow an f64", "1".repeat(700)))
+        );
+    }
+
+    #[test]
+    fn certified_comparisons() {
+        let ternary = LaminationAlgebra::<IntervalPoint>::new(3);
+
+        assert!(ternary.parse("1_021").unwrap().may_equal(&ternary.parse("_102").unwrap()));
+        assert!(ternary.parse("_2").unwrap().may_equal(&ternary.parse("_").unwrap()));
+        assert!(ternary.parse("_1").unwrap().provably_distinct(&ternary.parse("_12").unwrap()));
+        assert!(ternary.parse("_1").unwrap() < ternary.parse("_12").unwrap());
+
+        let wide = IntervalPoint::around(0.5, 0.1);
+        assert!(wide.may_equal(&IntervalPoint::around(0.55, 0.0)));
+        assert!(wide.provably_distinct(&IntervalPoint::around(0.7, 0.05)));
+    }
+
+    #[test]
+    fn orders_by_bounds() {
+        let (low, middle, high) = (
+            IntervalPoint::new(0.1, 0.3),
+            IntervalPoint::new(0.2, 0.4),
+            IntervalPoint::new(0.35, 0.5),
+        );
+        assert!(low.may_equal(&middle) && middle.may_equal(&high) && !low.may_equal(&high));
+        assert!(low < middle && middle < high);
+        assert_ne!(low, middle);
+        assert_eq!(middle, IntervalPoint::new(0.2, 0.4));
+        assert!(IntervalPoint::new(0.2, 0.3) < middle);
+    }
+
+    #[test]
+    fn certified_digits() {
+        let binary = LaminationAlgebra::<IntervalPoint>::new(2);
+
+        let (third_digits, _) = binary.format_digits(&binary.parse("_01").unwrap());
+        assert!(third_digits.len() > 40);
+        assert!(third_digits.chunks_exact(2).all(|pair| pair[0] == 0 && pair[1] == 1));
+        assert_eq!(
+            binary.format_digits(&IntervalPoint::around(0.375, 0.01)),
+            (vec![0, 1], vec![])
+        );
+        assert_eq!(IntervalPoint::new(0.25, 0.5).to_rational(2), Ratio::new(3, 8));
+
+        let tiny = |exponent: i32| IntervalPoint::new(2f64.powi(exponent), 2f64.powi(exponent));
+        assert_eq!(tiny(-127).to_rational(2), Ratio::new(1, 1 << 127));
+        assert_eq!(tiny(-128).to_rational(2), Ratio::new(1, 1 << 127));
+        assert_eq!(tiny(-129).to_rational(2), Ratio::from_integer(0));
+        assert_eq!(IntervalPoint::new(0.0, 0.0).to_rational(2), Ratio::from_integer(0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn rejects_infinite_bounds() {
+        IntervalPoint::new(0.0, f64::INFINITY);
+    }
+
+    #[test]
+    fn num_traits() {
+        let point = IntervalPoint::from_f64(0.1).unwrap();
+        assert_eq!(point.width(), 0.0);
+        assert_eq!(point.to_f64(), Some(0.1));
+        assert!(IntervalPoint::from_f64(0.1).unwrap().provably_distinct(&point.map(2)));
+        assert_eq!(IntervalPoint::from_f64(f64::NAN), None);
+    }
+
+    #[test]
+    fn dynamics() {
+        let binary = LaminationAlgebra::<IntervalPoint>::new(2);
+        let point = binary.parse("_001").unwrap();
+        let mapped = binary.map(&point);
+
+        assert!(mapped.may_equal(&binary.parse("_010").unwrap()));
+        assert!(mapped.width() > point.width());
+
+        let preimages = binary.preimages(&point).unwrap();
+        assert_eq!(preimages.len(), 2);
+        assert!(preimages[0].may_equal(&binary.parse("0_001").unwrap()));
+        assert!(preimages[1].may_equal(&binary.parse("1_001").unwrap()));
+    }
+}
@@ -0,0 +1,278 @@
+use super::{ExactUnitNumber, LaminationAlgebra};
+use std::cmp::Ordering;
+
+// Kneading sequences are written as the repeating word of a periodic sequence over '0', '1' and
+// '*', where '*' may only appear last.
+fn check_kneading(kneading: &str) -> Result<Vec<char>, String> {
+    let symbols: Vec<char> = kneading.chars().collect();
+    if let Some(c) = symbols.iter().find(|&&c| c != '0' && c != '1' && c != '*') {
+        return Err(format!("`{}` contains `{}`, not 0, 1 or *", kneading, c));
+    }
+    match symbols.iter().position(|&c| c == '*') {
+        Some(position) if position + 1 != symbols.len() => {
+            return Err(format!("`{}` contains * before its end", kneading));
+        }
+        _ => {}
+    }
+    match symbols.first() {
+        None => Err("the kneading sequence is empty".to_string()),
+        Some('0') => Err(format!("`{}` does not start with 1", kneading)),
+        Some(_) => Ok(symbols),
+    }
+}
+
+// The first position k > n with ν_k != ν_{k-n}, if any.
+fn rho(symbols: &[char], n: usize) -> Option<usize> {
+    let symbol = |k: usize| symbols[(k - 1) % symbols.len()];
+    (n + 1..=n + n * symbols.len()).find(|&k| symbol(k) != symbol(k - n))
+}
+
+fn rho_orbit(symbols: &[char], start: usize) -> Vec<usize> {
+    let mut orbit = vec![start];
+    while let Some(next) = rho(symbols, *orbit.last().unwrap()) {
+        orbit.push(next);
+    }
+    orbit
+}
+
+pub fn internal_address(kneading: &str) -> Result<Vec<usize>, String> {
+    let symbols = check_kneading(kneading)?;
+    Ok(rho_orbit(&symbols, 1))
+}
+
+// The Bruin-Schleicher admissibility condition, for *-periodic sequences only.
+pub fn is_admissible(kneading: &str) -> Result<bool, String> {
+    let symbols = check_kneading(kneading)?;
+    if symbols.last() != Some(&'*') {
+        return Err(format!("`{}` does not end with *", kneading));
+    }
+
+    let address = rho_orbit(&symbols, 1);
+    let fails_for_period = |m: usize| {
+        if address.contains(&m) {
+            return false;
+        }
+        let divisors_fail = (1..m)
+            .filter(|&k| m.is_multiple_of(k))
+            .all(|k| rho(&symbols, k).is_some_and(|next| next < m));
+        if !divisors_fail {
+            return false;
+        }
+        match rho(&symbols, m) {
+            Some(next) => rho_orbit(&symbols, (next - 1) % m + 1).contains(&m),
+            None => false,
+        }
+    };
+
+    Ok(!(1..symbols.len()).any(fails_for_period))
+}
+
+pub fn kneading_from_internal_address(address: &[usize]) -> Result<String, String> {
+    if address.first() != Some(&1) {
+        return Err(format!("internal address {:?} does not start with 1", address));
+    }
+    if address.windows(2).any(|pair| pair[0] >= pair[1]) {
+        return Err(format!("internal address {:?} is not increasing", address));
+    }
+
+    let mut symbols = vec!['1'];
+    for pair in address.windows(2) {
+        let (period, next) = (pair[0], pair[1]);
+        while symbols.len() + 1 < next {
+            symbols.push(symbols[symbols.len() - period]);
+        }
+        symbols.push(if symbols[next - 1 - period] == '1' { '0' } else { '1' });
+    }
+    *symbols.last_mut().unwrap() = '*';
+    Ok(symbols.into_iter().collect())
+}
+
+// Reads the kneading word as the itinerary of a real quadratic map, where 1 is the side of the
+// critical value, and returns log(1/s) for the smallest root s of the Milnor-Thurston kneading
+// determinant in (0, 1). For a repeating word of length n the determinant is a polynomial of
+// degree n - 1 divided by a factor with no roots in (0, 1), so no series truncation is needed.
+pub fn kneading_entropy(kneading: &str) -> Result<f64, String> {
+    let symbols = check_kneading(kneading)?;
+
+    let mut coefficients = vec![1.0];
+    for symbol in &symbols[..symbols.len() - 1] {
+        let sign = if *symbol == '1' { -1.0 } else { 1.0 };
+        coefficients.push(coefficients.last().unwrap() * sign);
+    }
+    let determinant = |t: f64| coefficients.iter().rev().fold(0.0, |sum, c| sum * t + c);
+
+    const STEPS: usize = 10_000;
+    let mut low = 0.0;
+    for step in 1..STEPS {
+        let high = step as f64 / STEPS as f64;
+        if determinant(high) <= 0.0 {
+            let mut high = high;
+            for _ in 0..64 {
+                let middle = (low + high) / 2.0;
+                if determinant(middle) > 0.0 {
+                    low = middle;
+                } else {
+                    high = middle;
+                }
+            }
+            return Ok(-high.ln());
+        }
+        low = high;
+    }
+    Ok(0.0)
+}
+
+impl<T: ExactUnitNumber> LaminationAlgebra<T> {
+    pub fn kneading_sequence(&self, angle: &T) -> Result<String, String> {
+        if self.base != 2 {
+            return Err(format!("kneading sequences need base 2, not {}", self.base));
+        }
+        let period = self
+            .period(angle)
+            .ok_or_else(|| format!("{} is not periodic", self.format(angle)))?;
+
+        let mut boundary = self.preimages(angle)?;
+        boundary.sort_by(|a, b| self.cmp(a, b));
+        let (low, high) = (&boundary[0], &boundary[1]);
+        let inside = |x: &T| {
+            self.cmp(low, x) == Ordering::Less && self.cmp(x, high) == Ordering::Less
+        };
+
+        let kneading = self
+            .orbit(angle)
+            .iter()
+            .take(period)
+            .map(|x| {
+                if x == low || x == high {
+                    '*'
+                } else if inside(x) {
+                    '1'
+                } else {
+                    '0'
+                }
+            })
+            .collect();
+        Ok(kneading)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::DefaultAlgebra;
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn computes_kneading_sequences() {
+        let binary = DefaultAlgebra::new(2);
+        let kneading = |s: &str| binary.kneading_sequence(&binary.parse(s).unwrap());
+
+        assert_eq!(kneading("_"), Ok("*".to_string()));
+        assert_eq!(kneading("_01"), Ok("1*".to_string()));
+        assert_eq!(kneading("_001"), Ok("11*".to_string()));
+        assert_eq!(kneading("_011"), Ok("10*".to_string()));
+        assert_eq!(kneading("_00011"), Ok("1110*".to_string()));
+        assert_eq!(kneading("0_01"), Err("0_01 is not periodic".to_string()));
+
+        let ternary = DefaultAlgebra::new(3);
+        assert_eq!(
+            ternary.kneading_sequence(&ternary.parse("_1").unwrap()),
+            Err("kneading sequences need base 2, not 3".to_string())
+        );
+    }
+
+    #[test]
+    fn converts_internal_addresses() {
+        assert_eq!(internal_address("*"), Ok(vec![1]));
+        assert_eq!(internal_address("1"), Ok(vec![1]));
+        assert_eq!(internal_address("1*"), Ok(vec![1, 2]));
+        assert_eq!(internal_address("11*"), Ok(vec![1, 3]));
+        assert_eq!(internal_address("10*"), Ok(vec![1, 2, 3]));
+        assert_eq!(internal_address("1100*"), Ok(vec![1, 3, 4, 5]));
+        assert_eq!(internal_address("10"), Ok(vec![1, 2]));
+
+        assert_eq!(kneading_from_internal_address(&[1]), Ok("*".to_string()));
+        assert_eq!(kneading_from_internal_address(&[1, 3]), Ok("11*".to_string()));
+        assert_eq!(kneading_from_internal_address(&[1, 2, 3]), Ok("10*".to_string()));
+        assert_eq!(kneading_from_internal_address(&[1, 3, 4, 5]), Ok("1100*".to_string()));
+
+        let binary = DefaultAlgebra::new(2);
+        for n in 1..=10 {
+            for numerator in 0..(1u128 << n) - 1 {
+                let angle = binary.parse(&format!("_{:0width$b}", numerator, width = n));
+                let kneading = binary.kneading_sequence(&angle.unwrap()).unwrap();
+                let address = internal_address(&kneading).unwrap();
+                assert_eq!(kneading_from_internal_address(&address), Ok(kneading));
+            }
+        }
+    }
+
+    #[test]
+    fn checks_admissibility() {
+        assert_eq!(is_admissible("*"), Ok(true));
+        assert_eq!(is_admissible("1110*"), Ok(true));
+        assert_eq!(is_admissible("1"), Err("`1` does not end with *".to_string()));
+
+        let unrealized = kneading_from_internal_address(&[1, 2, 4, 5, 6]).unwrap();
+        assert_eq!(is_admissible(&unrealized), Ok(false));
+
+        let binary = DefaultAlgebra::new(2);
+        for n in 2..=10 {
+            let mut realized = HashSet::new();
+            for numerator in 0..(1u128 << n) - 1 {
+                let angle = binary.parse(&format!("_{:0width$b}", numerator, width = n));
+                realized.insert(binary.kneading_sequence(&angle.unwrap()).unwrap());
+            }
+
+            for bits in 0..1u32 << (n - 2) {
+                let middle: String = (0..n - 2)
+                    .map(|i| if bits >> i & 1 == 1 { '1' } else { '0' })
+                    .collect();
+                let kneading = format!("1{}*", middle);
+                assert_eq!(is_admissible(&kneading), Ok(realized.contains(&kneading)));
+            }
+        }
+    }
+
+    #[test]
+    fn computes_entropy() {
+        let golden_ratio = (1.0 + 5f64.sqrt()) / 2.0;
+        let tribonacci: f64 = 1.839_286_755_214_161;
+
+        assert_eq!(kneading_entropy("*"), Ok(0.0));
+        assert_eq!(kneading_entropy("1*"), Ok(0.0));
+        assert!((kneading_entropy("10*").unwrap() - golden_ratio.ln()).abs() < 1e-12);
+        assert!((kneading_entropy("100*").unwrap() - tribonacci.ln()).abs() < 1e-12);
+        assert!((kneading_entropy("100").unwrap() - golden_ratio.ln()).abs() < 1e-12);
+        assert!(kneading_entropy("1000000*").unwrap() < 2f64.ln());
+    }
+
+    #[test]
+    fn rejects_malformed_input() {
+        assert_eq!(
+            internal_address(""),
+            Err("the kneading sequence is empty".to_string())
+        );
+        assert_eq!(
+            internal_address("1*0"),
+            Err("`1*0` contains * before its end".to_string())
+        );
+        assert_eq!(
+            internal_address("12"),
+            Err("`12` contains `2`, not 0, 1 or *".to_string())
+        );
+        assert_eq!(
+            internal_address("01*"),
+            Err("`01*` does not start with 1".to_string())
+        );
+        assert_eq!(
+            kneading_from_internal_address(&[2, 3]),
+            Err("internal address [2, 3] does not start with 1".to_string())
+        );
+        assert_eq!(
+            kneading_from_internal_address(&[1, 3, 3]),
+            Err("internal address [1, 3, 3] is not increasing".to_string())
+        );
+    }
+}
+
@@ -0,0 +1,149 @@
+use super::{LaminationAlgebra, Leaf, UnitNumber};
+use std::borrow::Cow;
+use std::fmt::{self, Write};
+use std::io::BufRead;
+
+const HEADER: &str = "base,endpoint_a,endpoint_b,generation";
+
+impl<T: UnitNumber> LaminationAlgebra<T> {
+    pub fn write_leaf_csv<W: Write>(
+        &self,
+        out: &mut W,
+        leaves: &[(usize, Leaf<T>)],
+    ) -> fmt::Result {
+        writeln!(out, "{}", HEADER)?;
+        for (generation, (a, b)) in leaves {
+            writeln!(
+                out,
+                "{},{},{},{}",
+                self.base,
+                csv_field(&self.format(a)),
+                csv_field(&self.format(b)),
+                generation
+            )?;
+        }
+        Ok(())
+    }
+
+    pub fn leaf_csv(&self, leaves: &[(usize, Leaf<T>)]) -> String {
+        let mut csv = String::new();
+        self.write_leaf_csv(&mut csv, leaves).unwrap();
+        csv
+    }
+
+    // Reads rows written by write_leaf_csv. The header is optional, blank lines are skipped, and
+    // every row's base has to match the algebra's.
+    pub fn read_leaf_csv<R: BufRead>(&self, reader: R) -> Result<Vec<(usize, Leaf<T>)>, String> {
+        let mut leaves = vec![];
+        for (index, line) in reader.lines().enumerate() {
+            let line_number = index + 1;
+            let line = line.map_err(|error| format!("line {}: {}", line_number, error))?;
+            if line.trim().is_empty() || (index == 0 && line.trim() == HEADER) {
+                continue;
+            }
+            let leaf = self
+                .leaf_from_row(&line)
+                .map_err(|error| format!("line {}: {}", line_number, error))?;
+            leaves.push(leaf);
+        }
+        Ok(leaves)
+    }
+
+    fn leaf_from_row(&self, line: &str) -> Result<(usize, Leaf<T>), String> {
+        let fields = csv_fields(line)?;
+        let (base, a, b, generation) = match fields.as_slice() {
+            [base, a, b, generation] => (base, a, b, generation),
+            _ => return Err(format!("expected 4 fields, found {}", fields.len())),
+        };
+
+        let base: u16 = base
+            .trim()
+            .parse()
+            .map_err(|_| format!("`{}` is not a valid base", base))?;
+        if base != self.base {
+            return Err(format!("base {} does not match the algebra's base {}", base, self.base));
+        }
+        let generation = generation
+            .trim()
+            .parse()
+            .map_err(|_| format!("`{}` is not a valid generation", generation))?;
+        Ok((generation, (self.parse(a.trim())?, self.parse(b.trim())?)))
+    }
+}
+
+fn csv_field(value: &str) -> Cow<'_, str> {
+    if value.contains([',', '"']) {
+        Cow::Owned(format!("\"{}\"", value.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(value)
+    }
+}
+
+fn csv_fields(line: &str) -> Result<Vec<String>, String> {
+    let mut fields = vec![];
+    let mut field = String::new();
+    let mut chars = line.chars().peekable();
+    let mut quoted = false;
+
+    while let Some(c) = chars.next() {
+        match (quoted, c) {
+            (true, '"') if chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            (true, '"') => quoted = false,
+            (false, '"') if field.is_empty() => quoted = true,
+            (false, ',') => fields.push(std::mem::take(&mut field)),
+            (_, c) => field.push(c),
+        }
+    }
+    if quoted {
+        return Err(format!("`{}` has an unterminated quote", line));
+    }
+    fields.push(field);
+    Ok(fields)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::DefaultAlgebra;
+    use super::super::test_support::pair;
+
+    #[test]
+    fn writes_and_reads_leaf_csv() {
+        let dozenal = DefaultAlgebra::new(12);
+        let leaves = vec![(0, pair(&dozenal, "_1", "_11,9,2")), (2, pair(&dozenal, "3_", "_"))];
+
+        let csv = dozenal.leaf_csv(&leaves);
+        assert_eq!(
+            csv,
+            "base,endpoint_a,endpoint_b,generation
+12,_1,\"_11,9,2\",0
+12,3_,_,2
+"
+        );
+        assert_eq!(dozenal.read_leaf_csv(csv.as_bytes()), Ok(leaves));
+    }
+
+    #[test]
+    fn reports_bad_rows() {
+        let binary = DefaultAlgebra::new(2);
+        let read = |csv: &str| binary.read_leaf_csv(csv.as_bytes());
+
+        assert_eq!(read("2,_01,_10,1\n\n2,_,_,0\n").map(|leaves| leaves.len()), Ok(2));
+        assert_eq!(
+            read("2,_01,_10,1\n3,_1,_2,0\n"),
+            Err("line 2: base 3 does not match the algebra's base 2".to_string())
+        );
+        assert_eq!(read("2,_01,_10\n"), Err("line 1: expected 4 fields, found 3".to_string()));
+        assert_eq!(
+            read("2,\"_01,_10,1\n"),
+            Err("line 1: `2,\"_01,_10,1` has an unterminated quote".to_string())
+        );
+        assert_eq!(
+            read("2,_01,_10,first\n"),
+            Err("line 1: `first` is not a valid generation".to_string())
+        );
+        assert!(read("2,_01,_1x,0\n").unwrap_err().starts_with("line 1: "));
+    }
+}
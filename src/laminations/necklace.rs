@@ -0,0 +1,90 @@
+use super::minimal_period;
+
+pub fn necklace(word: &[u16]) -> Vec<u16> {
+    (0..word.len().max(1))
+        .map(|shift| word[shift..].iter().chain(&word[..shift]).copied().collect::<Vec<u16>>())
+        .min()
+        .unwrap_or_default()
+}
+
+// The least rotation of an aperiodic word, or None if the word repeats a shorter block.
+pub fn lyndon_rotation(word: &[u16]) -> Option<Vec<u16>> {
+    if word.is_empty() || minimal_period(word) != word.len() {
+        return None;
+    }
+    Some(necklace(word))
+}
+
+// Duval's algorithm, keeping only the words of exactly length n.
+pub fn lyndon_words(base: u16, len: usize) -> Vec<Vec<u16>> {
+    let mut words = vec![];
+    if len == 0 {
+        return words;
+    }
+
+    let mut word = vec![0u16];
+    loop {
+        if word.len() == len {
+            words.push(word.clone());
+        }
+        let period = word.len();
+        while word.len() < len {
+            word.push(word[word.len() - period]);
+        }
+        while word.last() == Some(&(base - 1)) {
+            word.pop();
+        }
+        match word.last_mut() {
+            Some(digit) => *digit += 1,
+            None => return words,
+        }
+    }
+}
+
+pub fn aperiodic_words(base: u16, len: usize) -> Vec<Vec<u16>> {
+    let mut words: Vec<Vec<u16>> = lyndon_words(base, len)
+        .iter()
+        .flat_map(|word| {
+            (0..len).map(move |shift| word[shift..].iter().chain(&word[..shift]).copied().collect())
+        })
+        .collect();
+    words.sort();
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rotates() {
+        assert_eq!(necklace(&[]), vec![]);
+        assert_eq!(necklace(&[1, 0, 1, 1]), vec![0, 1, 1, 1]);
+        assert_eq!(necklace(&[1, 0, 1, 0]), vec![0, 1, 0, 1]);
+        assert_eq!(necklace(&[2, 1, 0]), vec![0, 2, 1]);
+
+        assert_eq!(lyndon_rotation(&[1, 0, 1, 1]), Some(vec![0, 1, 1, 1]));
+        assert_eq!(lyndon_rotation(&[1, 0, 1, 0]), None);
+        assert_eq!(lyndon_rotation(&[]), None);
+    }
+
+    #[test]
+    fn enumerates() {
+        assert_eq!(lyndon_words(2, 0), Vec::<Vec<u16>>::new());
+        assert_eq!(lyndon_words(2, 1), vec![vec![0], vec![1]]);
+        assert_eq!(
+            lyndon_words(2, 4),
+            vec![vec![0, 0, 0, 1], vec![0, 0, 1, 1], vec![0, 1, 1, 1]]
+        );
+        assert_eq!(lyndon_words(3, 2), vec![vec![0, 1], vec![0, 2], vec![1, 2]]);
+
+        let counts: Vec<usize> = (1..=8).map(|len| lyndon_words(2, len).len()).collect();
+        assert_eq!(counts, vec![2, 1, 2, 3, 6, 9, 18, 30]);
+
+        assert_eq!(aperiodic_words(2, 2), vec![vec![0, 1], vec![1, 0]]);
+        assert_eq!(aperiodic_words(3, 4).len(), 72);
+        for word in aperiodic_words(3, 4) {
+            assert!(lyndon_words(3, 4).contains(&lyndon_rotation(&word).unwrap()));
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use super::{ExactUnitNumber, LaminationAlgebra};
+use std::fmt::{self, Write};
+
+impl<T: ExactUnitNumber> LaminationAlgebra<T> {
+    pub fn write_orbit_table<W: Write>(&self, out: &mut W, x: &T) -> fmt::Result {
+        let header = ["n", "digits", "fraction", "float"].map(String::from);
+        let rows: Vec<[String; 4]> = self
+            .orbit(x)
+            .iter()
+            .enumerate()
+            .map(|(index, point)| {
+                [
+                    index.to_string(),
+                    self.format(point),
+                    self.format_fraction(point),
+                    format!("{:.6}", point.to_float(self.base)),
+                ]
+            })
+            .collect();
+
+        let mut widths = [0; 4];
+        for row in std::iter::once(&header).chain(&rows) {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.len());
+            }
+        }
+
+        for row in std::iter::once(&header).chain(&rows) {
+            writeln!(
+                out,
+                "{:>w0$}  {:<w1$}  {:<w2$}  {}",
+                row[0],
+                row[1],
+                row[2],
+                row[3],
+                w0 = widths[0],
+                w1 = widths[1],
+                w2 = widths[2],
+            )?;
+        }
+        Ok(())
+    }
+
+    pub fn orbit_table(&self, x: &T) -> String {
+        let mut table = String::new();
+        self.write_orbit_table(&mut table, x).unwrap();
+        table
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::DefaultAlgebra;
+
+    #[test]
+    fn formats_orbit_table() {
+        let binary = DefaultAlgebra::new(2);
+
+        assert_eq!(
+            binary.orbit_table(&binary.parse("0_001").unwrap()),
+            "n  digits  fraction  float
+0  0_001   1/14      0.071429
+1  _001    1/7       0.142857
+2  _010    2/7       0.285714
+3  _100    4/7       0.571429
+"
+        );
+    }
+}
@@ -0,0 +1,215 @@
+use super::{ExactUnitNumber, LaminationAlgebra, UnitNumber};
+use num::rational::BigRational;
+use num::One;
+use std::cmp::Ordering;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OrbitPortrait<T: UnitNumber> {
+    pub sets: Vec<Vec<T>>,
+    pub ray_period: usize,
+}
+
+impl<T: UnitNumber> OrbitPortrait<T> {
+    pub fn orbit_period(&self) -> usize {
+        self.sets.len()
+    }
+
+    pub fn valence(&self) -> usize {
+        self.sets[0].len()
+    }
+}
+
+impl<T: ExactUnitNumber> LaminationAlgebra<T> {
+    pub fn orbit_portrait(&self, angles: &[T]) -> Result<OrbitPortrait<T>, String> {
+        if angles.is_empty() {
+            return Err("an orbit portrait needs at least one angle".to_string());
+        }
+
+        let ray_period = self.ray_period(angles)?;
+        let first = self.sorted_set(angles.to_vec());
+        let mut sets = vec![first.clone()];
+        loop {
+            let image = self.sorted_set(self.map_batch(sets.last().unwrap()));
+            if image.len() != first.len() {
+                return Err(format!(
+                    "{} is not mapped injectively",
+                    self.format_set(sets.last().unwrap())
+                ));
+            }
+            if image == first {
+                break;
+            }
+            if let Some(set) = sets.iter().find(|set| set.iter().any(|x| image.contains(x))) {
+                return Err(format!(
+                    "{} and {} overlap",
+                    self.format_set(set),
+                    self.format_set(&image)
+                ));
+            }
+            sets.push(image);
+        }
+
+        for (index, a) in sets.iter().enumerate() {
+            for b in &sets[index + 1..] {
+                if self.linked(a, b) {
+                    return Err(format!(
+                        "{} and {} are linked",
+                        self.format_set(a),
+                        self.format_set(b)
+                    ));
+                }
+            }
+        }
+
+        Ok(OrbitPortrait { sets, ray_period })
+    }
+
+    // The endpoints of the shortest complementary arc over all sets, in counterclockwise order.
+    // Portraits of valence 1 have no characteristic arc.
+    pub fn characteristic_angles(&self, portrait: &OrbitPortrait<T>) -> Option<(T, T)> {
+        if portrait.valence() < 2 {
+            return None;
+        }
+
+        portrait
+            .sets
+            .iter()
+            .flat_map(|set| {
+                let next = set.iter().cycle().skip(1);
+                set.iter().zip(next).map(|(a, b)| (a.clone(), b.clone()))
+            })
+            .min_by_key(|(a, b)| self.arc_length(a, b))
+    }
+
+    pub fn wake(&self, angles: &[T]) -> Result<(T, T), String> {
+        let portrait = self.orbit_portrait(angles)?;
+        self.characteristic_angles(&portrait)
+            .ok_or_else(|| "a portrait of valence 1 has no wake".to_string())
+    }
+
+    fn arc_length(&self, a: &T, b: &T) -> BigRational {
+        let (a, b) = (self.to_big_rational(a), self.to_big_rational(b));
+        if a < b {
+            b - a
+        } else {
+            BigRational::one() - (a - b)
+        }
+    }
+
+    fn ray_period(&self, angles: &[T]) -> Result<usize, String> {
+        let mut ray_period = None;
+        for angle in angles {
+            let period = self
+                .period(angle)
+                .ok_or_else(|| format!("{} is not periodic", self.format(angle)))?;
+            match ray_period {
+                Some(ray_period) if ray_period != period => {
+                    return Err(format!(
+                        "{} has period {}, not {}",
+                        self.format(angle),
+                        period,
+                        ray_period
+                    ));
+                }
+                _ => ray_period = Some(period),
+            }
+        }
+        Ok(ray_period.unwrap())
+    }
+
+    fn sorted_set(&self, mut set: Vec<T>) -> Vec<T> {
+        set.sort_by(|a, b| self.cmp(a, b));
+        set.dedup();
+        set
+    }
+
+    // Two disjoint sorted sets are unlinked when `b` lies in a single complementary arc of `a`.
+    fn linked(&self, a: &[T], b: &[T]) -> bool {
+        let arc_index = |x: &T| {
+            a.iter().filter(|y| self.cmp(y, x) == Ordering::Less).count() % a.len()
+        };
+        let first = arc_index(&b[0]);
+        b.iter().any(|x| arc_index(x) != first)
+    }
+
+    fn format_set(&self, set: &[T]) -> String {
+        let points: Vec<String> = set.iter().map(|x| self.format(x)).collect();
+        format!("{{{}}}", points.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::DefaultAlgebra;
+    use super::super::test_support::{pair, parse_all};
+
+    #[test]
+    fn computes_portraits() {
+        let binary = DefaultAlgebra::new(2);
+
+        let rabbit = binary.orbit_portrait(&parse_all(&binary, &["_100", "_001", "_010"])).unwrap();
+        assert_eq!(rabbit.sets, vec![parse_all(&binary, &["_001", "_010", "_100"])]);
+        assert_eq!(rabbit.orbit_period(), 1);
+        assert_eq!(rabbit.valence(), 3);
+        assert_eq!(rabbit.ray_period, 3);
+
+        let airplane = binary.orbit_portrait(&parse_all(&binary, &["_011", "_100"])).unwrap();
+        assert_eq!(
+            airplane.sets,
+            vec![
+                parse_all(&binary, &["_011", "_100"]),
+                parse_all(&binary, &["_001", "_110"]),
+                parse_all(&binary, &["_010", "_101"]),
+            ]
+        );
+        assert_eq!(airplane.orbit_period(), 3);
+        assert_eq!(airplane.valence(), 2);
+        assert_eq!(airplane.ray_period, 3);
+
+        let fixed = binary.orbit_portrait(&parse_all(&binary, &["_"])).unwrap();
+        assert_eq!(fixed.sets, vec![parse_all(&binary, &["_"])]);
+    }
+
+    #[test]
+    fn finds_characteristic_angles() {
+        let binary = DefaultAlgebra::new(2);
+
+        let basilica = binary.orbit_portrait(&parse_all(&binary, &["_01", "_10"])).unwrap();
+        assert_eq!(binary.characteristic_angles(&basilica), Some(pair(&binary, "_01", "_10")));
+
+        assert_eq!(
+            binary.wake(&parse_all(&binary, &["_001", "_010", "_100"])),
+            Ok(pair(&binary, "_001", "_010"))
+        );
+        assert_eq!(
+            binary.wake(&parse_all(&binary, &["_011", "_100"])),
+            Ok(pair(&binary, "_011", "_100"))
+        );
+        assert_eq!(
+            binary.wake(&parse_all(&binary, &["_"])),
+            Err("a portrait of valence 1 has no wake".to_string())
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_portraits() {
+        let binary = DefaultAlgebra::new(2);
+
+        assert_eq!(
+            binary.orbit_portrait(&parse_all(&binary, &["0_01"])),
+            Err("0_01 is not periodic".to_string())
+        );
+        assert_eq!(
+            binary.orbit_portrait(&parse_all(&binary, &["_01", "_001"])),
+            Err("_001 has period 3, not 2".to_string())
+        );
+        assert_eq!(
+            binary.orbit_portrait(&parse_all(&binary, &["_001", "_010"])),
+            Err("{_001, _010} and {_010, _100} overlap".to_string())
+        );
+        assert_eq!(
+            binary.orbit_portrait(&parse_all(&binary, &["_001", "_101"])),
+            Err("{_001, _101} and {_100, _110} are linked".to_string())
+        );
+    }
+}
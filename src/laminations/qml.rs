@@ -0,0 +1,223 @@
+use super::{DefaultAlgebra, ExactUnitNumber, LaminationAlgebra, Leaf, UnitFraction};
+use num::rational::BigRational;
+use num::One;
+
+pub type MinorLeaf = Leaf<UnitFraction>;
+
+impl<T: ExactUnitNumber> LaminationAlgebra<T> {
+    // Thurston's characterization: a chord is a minor of the quadratic minor lamination when no
+    // forward image is shorter than it, crosses it, or crosses another image. Degenerate chords
+    // are always leaves.
+    pub fn in_qml(&self, a: &T, b: &T) -> Result<bool, String> {
+        if self.base != 2 {
+            return Err(format!("the quadratic minor lamination needs base 2, not {}", self.base));
+        }
+        if a == b {
+            return Ok(true);
+        }
+
+        let minor = self.sorted_chord(a.clone(), b.clone());
+        let length = self.chord_length(&minor);
+        let mut images: Vec<(T, T)> = vec![];
+        let mut image = minor.clone();
+        loop {
+            image = self.sorted_chord(self.map(&image.0), self.map(&image.1));
+            if image == minor || images.contains(&image) {
+                break;
+            }
+            if self.chord_length(&image) < length
+                || self.crosses(&image, &minor)
+                || images.iter().any(|other| self.crosses(&image, other))
+            {
+                return Ok(false);
+            }
+            images.push(image.clone());
+        }
+        Ok(true)
+    }
+
+    // Wakes never contain 0, so they run between the smaller and larger angle. The bounding angles
+    // themselves count as inside.
+    pub fn in_wake(&self, angle: &T, wake: &(T, T)) -> bool {
+        let wake = self.sorted_chord(wake.0.clone(), wake.1.clone());
+        *angle == wake.0 || *angle == wake.1 || self.strictly_between(angle, &wake)
+    }
+
+    fn chord_length(&self, chord: &(T, T)) -> BigRational {
+        let arc = self.to_big_rational(&chord.1) - self.to_big_rational(&chord.0);
+        let complement = BigRational::one() - &arc;
+        arc.min(complement)
+    }
+}
+
+impl DefaultAlgebra {
+    // Lavaurs' algorithm: working up from period 2, join the least unjoined angle of each period
+    // to the next unjoined angle of that period whose chord crosses no earlier chord.
+    pub fn minor_leaves(&self, period: usize) -> Result<Vec<MinorLeaf>, String> {
+        Ok(self
+            .minor_leaves_up_to(period)?
+            .into_iter()
+            .filter(|(current, _)| *current == period)
+            .map(|(_, chord)| chord)
+            .collect())
+    }
+
+    // The minors of every period up to `max_period`, tagged with their period. Lavaurs' algorithm
+    // needs all lower periods anyway, so this costs the same as minor_leaves(max_period).
+    pub fn minor_leaves_up_to(&self, max_period: usize) -> Result<Vec<(usize, MinorLeaf)>, String> {
+        if self.base != 2 {
+            return Err(format!("the quadratic minor lamination needs base 2, not {}", self.base));
+        }
+
+        let mut chords: Vec<(usize, MinorLeaf)> = vec![];
+        for current in 2..=max_period {
+            let mut unjoined = self.periodic_points(current)?;
+            while !unjoined.is_empty() {
+                let start = unjoined.remove(0);
+                let partner = (0..unjoined.len())
+                    .find(|&index| {
+                        let chord = (start.clone(), unjoined[index].clone());
+                        !chords.iter().any(|(_, other)| self.crosses(&chord, other))
+                    })
+                    .ok_or_else(|| format!("{} has no partner", self.format(&start)))?;
+                chords.push((current, (start, unjoined.remove(partner))));
+            }
+        }
+        Ok(chords)
+    }
+
+    // The other endpoint of the minor leaf at a periodic angle. Testing the angles of one period
+    // against Thurston's criterion avoids building the minors of every lower period. Fixed angles
+    // are degenerate leaves and are their own companions.
+    pub fn companion(&self, angle: &UnitFraction) -> Result<UnitFraction, String> {
+        if self.base != 2 {
+            return Err(format!("the quadratic minor lamination needs base 2, not {}", self.base));
+        }
+        let period = self
+            .period(angle)
+            .ok_or_else(|| format!("{} is not periodic", self.format(angle)))?;
+        if period == 1 {
+            return Ok(angle.clone());
+        }
+
+        for candidate in self.periodic_points(period)? {
+            if candidate != *angle && self.in_qml(angle, &candidate)? {
+                return Ok(candidate);
+            }
+        }
+        Err(format!("{} has no companion", self.format(angle)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::DefaultAlgebra;
+    use super::super::test_support::{pair, pairs};
+
+    #[test]
+    fn tests_qml_membership() {
+        let binary = DefaultAlgebra::new(2);
+        let in_qml = |a: &str, b: &str| {
+            binary.in_qml(&binary.parse(a).unwrap(), &binary.parse(b).unwrap())
+        };
+
+        assert_eq!(in_qml("_01", "_10"), Ok(true));
+        assert_eq!(in_qml("_010", "_001"), Ok(true));
+        assert_eq!(in_qml("_011", "_100"), Ok(true));
+        assert_eq!(in_qml("01_10", "10_01"), Ok(true));
+        assert_eq!(in_qml("0_01", "0_01"), Ok(true));
+        assert_eq!(in_qml("_001", "_100"), Ok(false));
+        assert_eq!(in_qml("_001", "_110"), Ok(false));
+
+        let ternary = DefaultAlgebra::new(3);
+        let point = ternary.parse("_1").unwrap();
+        assert_eq!(
+            ternary.in_qml(&point, &point),
+            Err("the quadratic minor lamination needs base 2, not 3".to_string())
+        );
+    }
+
+    #[test]
+    fn counts_periodic_minors() {
+        let binary = DefaultAlgebra::new(2);
+        let counts: Vec<usize> = (2..=7)
+            .map(|period| {
+                let points = binary.periodic_points(period).unwrap();
+                let mut count = 0;
+                for (index, a) in points.iter().enumerate() {
+                    for b in &points[index + 1..] {
+                        if binary.in_qml(a, b).unwrap() {
+                            count += 1;
+                        }
+                    }
+                }
+                count
+            })
+            .collect();
+        assert_eq!(counts, vec![1, 3, 6, 15, 27, 63]);
+    }
+
+    #[test]
+    fn tests_wake_containment() {
+        let binary = DefaultAlgebra::new(2);
+        let parse = |s: &str| binary.parse(s).unwrap();
+        let rabbit = (parse("_010"), parse("_001"));
+
+        assert!(binary.in_wake(&parse("0_01"), &rabbit));
+        assert!(binary.in_wake(&parse("_001"), &rabbit));
+        assert!(binary.in_wake(&parse("_010"), &rabbit));
+        assert!(!binary.in_wake(&parse("_0010"), &rabbit));
+        assert!(!binary.in_wake(&parse("_"), &rabbit));
+        assert!(!binary.in_wake(&parse("_011"), &rabbit));
+
+        let basilica = (parse("_01"), parse("_10"));
+        let inside: Vec<_> = binary
+            .periodic_points(4)
+            .unwrap()
+            .into_iter()
+            .filter(|angle| binary.in_wake(angle, &basilica))
+            .collect();
+        assert_eq!(inside, binary.parse_all(vec!["_0110", "_0111", "_1000", "_1001"]).unwrap());
+    }
+
+    #[test]
+    fn enumerates_minor_leaves() {
+        let binary = DefaultAlgebra::new(2);
+
+        assert_eq!(binary.minor_leaves(1), Ok(vec![]));
+        assert_eq!(binary.minor_leaves(2), Ok(vec![pair(&binary, "_01", "_10")]));
+        assert_eq!(
+            binary.minor_leaves(3),
+            Ok(pairs(&binary, &[("_001", "_010"), ("_011", "_100"), ("_101", "_110")]))
+        );
+
+        let counts: Vec<usize> = (2..=9)
+            .map(|period| binary.minor_leaves(period).unwrap().len())
+            .collect();
+        assert_eq!(counts, vec![1, 3, 6, 15, 27, 63, 120, 252]);
+
+        for (a, b) in binary.minor_leaves(6).unwrap() {
+            assert_eq!(binary.in_qml(&a, &b), Ok(true));
+        }
+
+        let all = binary.minor_leaves_up_to(5).unwrap();
+        assert_eq!(all.len(), 1 + 3 + 6 + 15);
+        assert_eq!(all[0], (2, pair(&binary, "_01", "_10")));
+        assert!(all.iter().all(|(period, (a, _))| binary.period(a) == Some(*period)));
+    }
+
+    #[test]
+    fn finds_companions() {
+        let binary = DefaultAlgebra::new(2);
+        let companion = |s: &str| binary.companion(&binary.parse(s).unwrap());
+
+        assert_eq!(companion("_"), Ok(binary.parse("_").unwrap()));
+        assert_eq!(companion("_001"), Ok(binary.parse("_010").unwrap()));
+        assert_eq!(companion("_010"), Ok(binary.parse("_001").unwrap()));
+        assert_eq!(companion("0_01"), Err("0_01 is not periodic".to_string()));
+        for (a, b) in binary.minor_leaves(7).unwrap() {
+            assert_eq!(binary.companion(&a).as_ref(), Ok(&b));
+            assert_eq!(binary.companion(&b).as_ref(), Ok(&a));
+        }
+    }
+}
@@ -0,0 +1,188 @@
+use super::{digits_from_rational, UnitFraction, ExactUnitNumber, UnitNumber};
+use num::rational::Ratio;
+use num::Zero;
+use std::cmp::Ordering;
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RationalPoint(Ratio<u128>);
+
+impl RationalPoint {
+    pub fn new(value: Ratio<u128>) -> RationalPoint {
+        RationalPoint(value.fract())
+    }
+
+    pub fn value(&self) -> &Ratio<u128> {
+        &self.0
+    }
+}
+
+impl From<Ratio<u128>> for RationalPoint {
+    fn from(value: Ratio<u128>) -> Self {
+        RationalPoint::new(value)
+    }
+}
+
+impl From<RationalPoint> for Ratio<u128> {
+    fn from(point: RationalPoint) -> Self {
+        point.0
+    }
+}
+
+impl ExactUnitNumber for RationalPoint {}
+
+impl UnitNumber for RationalPoint {
+    fn parse_nary(base: u16, s: &str) -> Result<Self, String> {
+        let parts: Vec<&str> = s.split('/').collect();
+        match parts.as_slice() {
+            [numerator, denominator] => {
+                let parse_part = |part: &str| -> Result<u128, String> {
+                    part.trim()
+                        .parse::<u128>()
+                        .map_err(|_| format!("{}: `{}` is not numerical", s, part))
+                };
+                let denominator = parse_part(denominator)?;
+                if denominator.is_zero() {
+                    return Err(format!("{}: denominator is zero", s));
+                }
+                Ok(RationalPoint::new(Ratio::new(
+                    parse_part(numerator)?,
+                    denominator,
+                )))
+            }
+            [_] => Ok(RationalPoint::new(
+                UnitFraction::parse_nary(base, s)?.to_rational(base),
+            )),
+            _ => Err(format!("`{}` contains more than one slash", s)),
+        }
+    }
+
+    fn to_rational(&self, _base: u16) -> Ratio<u128> {
+        self.0
+    }
+
+    fn to_digits(&self, base: u16) -> (Vec<u16>, Vec<u16>) {
+        digits_from_rational(base, &self.0)
+    }
+
+    // b p / q mod 1 is (b p mod q) / q, which never needs more than q.
+    fn map(&self, base: u16) -> Self {
+        let (numerator, denominator) = (*self.0.numer(), *self.0.denom());
+        RationalPoint(Ratio::new(mul_mod(numerator, base as u128, denominator), denominator))
+    }
+
+    // (p / q + d) / b is (p + d q) / (b q), and p + d q < b q.
+    fn preimages(&self, base: u16) -> Result<Vec<Self>, String> {
+        let (numerator, denominator) = (*self.0.numer(), *self.0.denom());
+        let scaled = denominator.checked_mul(base as u128).ok_or_else(|| {
+            format!("preimages of {} in base {} do not fit in Ratio<u128>", self.0, base)
+        })?;
+        Ok((0..base as u128)
+            .map(|digit| RationalPoint(Ratio::new(numerator + digit * denominator, scaled)))
+            .collect())
+    }
+
+    fn cmp_in_base(&self, other: &Self, _base: u16) -> Ordering {
+        self.cmp(other)
+    }
+}
+
+// a b mod m by doubling, so no intermediate exceeds m.
+fn mul_mod(a: u128, mut b: u128, m: u128) -> u128 {
+    let add_mod = |x: u128, y: u128| if x >= m - y { x - (m - y) } else { x + y };
+    let (mut result, mut a) = (0, a % m);
+    while b > 0 {
+        if b & 1 == 1 {
+            result = add_mod(result, a);
+        }
+        a = add_mod(a, a);
+        b >>= 1;
+    }
+    result
+}
+
+impl_primitive_conversions!(
+    RationalPoint,
+    zero: RationalPoint::new(Ratio::zero()),
+    to_f64: |x| x.0.to_f64(),
+    from_f64: |n| if n.is_finite() {
+        Ratio::from_f64(n.rem_euclid(1.0)).map(RationalPoint::new)
+    } else {
+        None
+    },
+);
+
+#[cfg(test)]
+mod tests {
+    use super::super::LaminationAlgebra;
+    use super::*;
+    use num::{FromPrimitive, ToPrimitive};
+    use std::convert::TryFrom;
+
+    #[test]
+    fn parse_rational() {
+        let ternary = LaminationAlgebra::<RationalPoint>::new(3);
+
+        assert_eq!(*ternary.parse("5/8").unwrap().value(), Ratio::new(5, 8));
+        assert_eq!(*ternary.parse("10/8").unwrap().value(), Ratio::new(1, 4));
+        assert_eq!(*ternary.parse("1_021").unwrap().value(), Ratio::new(11, 26));
+        assert_eq!(ternary.parse("_2").unwrap(), ternary.parse("0/1").unwrap());
+        assert!(ternary.parse("1/0").is_err());
+        assert!(ternary.parse("1/2/3").is_err());
+        assert!(ternary.parse("a/3").is_err());
+    }
+
+    #[test]
+    fn derives_digits() {
+        let ternary = LaminationAlgebra::<RationalPoint>::new(3);
+        let dozenal = LaminationAlgebra::<RationalPoint>::new(12);
+
+        assert_eq!(ternary.format(&ternary.parse("11/26").unwrap()), "_102");
+        assert_eq!(ternary.format(&ternary.parse("1/2").unwrap()), "_1");
+        assert_eq!(ternary.format(&ternary.parse("2/27").unwrap()), "002_");
+        assert_eq!(ternary.format(&ternary.parse("0/1").unwrap()), "_");
+        assert_eq!(dozenal.format(&dozenal.parse("1/3").unwrap()), "4_");
+        assert!(ternary.parse("1/3").unwrap() < ternary.parse("1/2").unwrap());
+    }
+
+    #[test]
+    fn num_traits() {
+        assert_eq!(*RationalPoint::from_f64(0.375).unwrap().value(), Ratio::new(3, 8));
+        assert_eq!(*RationalPoint::from_f64(-0.25).unwrap().value(), Ratio::new(3, 4));
+        assert_eq!(RationalPoint::from_f64(f64::INFINITY), None);
+        assert_eq!(RationalPoint::new(Ratio::new(5, 8)).to_f64(), Some(0.625));
+        assert_eq!(RationalPoint::from_i64(-7).unwrap().to_i64(), Some(0));
+    }
+
+    #[test]
+    fn conversions() {
+        let point = RationalPoint::from(Ratio::new(7, 4));
+        assert_eq!(Ratio::from(point), Ratio::new(3, 4));
+        assert_eq!(RationalPoint::try_from(0.5).unwrap(), RationalPoint::from(Ratio::new(1, 2)));
+        assert!(RationalPoint::try_from(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn dynamics() {
+        let binary = LaminationAlgebra::<RationalPoint>::new(2);
+        let point = binary.parse("1/5").unwrap();
+
+        assert_eq!(binary.map(&point), binary.parse("2/5").unwrap());
+        assert_eq!(binary.period(&point), Some(4));
+        assert_eq!(binary.period(&binary.parse("1/6").unwrap()), None);
+        assert_eq!(
+            binary.preimages(&point).unwrap(),
+            vec![binary.parse("1/10").unwrap(), binary.parse("3/5").unwrap()]
+        );
+
+        let huge = RationalPoint::new(Ratio::new(u128::MAX - 1, u128::MAX));
+        assert_eq!(binary.map(&huge), RationalPoint::new(Ratio::new(u128::MAX - 2, u128::MAX)));
+        assert_eq!(
+            binary.preimages(&huge),
+            Err(format!(
+                "preimages of {}/{} in base 2 do not fit in Ratio<u128>",
+                u128::MAX - 1,
+                u128::MAX
+            ))
+        );
+    }
+}
@@ -0,0 +1,83 @@
+use super::{DefaultAlgebra, InBase, UnitFraction};
+use num::rational::Ratio;
+use num::{One, Zero};
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+
+impl DefaultAlgebra {
+    pub fn conjugate(&self, angle: &UnitFraction) -> UnitFraction {
+        let rational = self.to_rational(angle);
+        let conjugate = if rational.is_zero() { rational } else { Ratio::one() - rational };
+        UnitFraction::try_from(InBase::new(self.base, conjugate)).unwrap()
+    }
+
+    // A periodic angle is real when it and its conjugate bound the wake of a real hyperbolic
+    // component, or when it is fixed by conjugation.
+    pub fn is_real_angle(&self, angle: &UnitFraction) -> Result<bool, String> {
+        if self.base != 2 {
+            return Err(format!("real angles need base 2, not {}", self.base));
+        }
+        if self.period(angle).is_none() {
+            return Err(format!("{} is not periodic", self.format(angle)));
+        }
+
+        let conjugate = self.conjugate(angle);
+        if conjugate == *angle {
+            return Ok(true);
+        }
+        let pair = match self.cmp(angle, &conjugate) {
+            Ordering::Less => (angle.clone(), conjugate.clone()),
+            _ => (conjugate.clone(), angle.clone()),
+        };
+        Ok(self.wake(&[angle.clone(), conjugate]) == Ok(pair))
+    }
+
+    pub fn real_periodic_angles(&self, period: usize) -> Result<Vec<UnitFraction>, String> {
+        if self.base != 2 {
+            return Err(format!("real angles need base 2, not {}", self.base));
+        }
+
+        let mut angles = vec![];
+        for angle in self.periodic_points(period)? {
+            if self.is_real_angle(&angle)? {
+                angles.push(angle);
+            }
+        }
+        Ok(angles)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conjugates() {
+        let binary = DefaultAlgebra::new(2);
+        let conjugate = |s: &str| binary.format(&binary.conjugate(&binary.parse(s).unwrap()));
+
+        assert_eq!(conjugate("_"), "_");
+        assert_eq!(conjugate("_01"), "_10");
+        assert_eq!(conjugate("0_011"), "1_100");
+    }
+
+    #[test]
+    fn finds_real_angles() {
+        let binary = DefaultAlgebra::new(2);
+        let is_real = |s: &str| binary.is_real_angle(&binary.parse(s).unwrap());
+
+        assert_eq!(is_real("_"), Ok(true));
+        assert_eq!(is_real("_01"), Ok(true));
+        assert_eq!(is_real("_011"), Ok(true));
+        assert_eq!(is_real("_001"), Ok(false));
+        assert_eq!(is_real("0_01"), Err("0_01 is not periodic".to_string()));
+
+        let angles = binary.real_periodic_angles(4).unwrap();
+        assert_eq!(angles, binary.parse_all(vec!["_0110", "_0111", "_1000", "_1001"]).unwrap());
+
+        let counts: Vec<usize> = (1..=10)
+            .map(|period| binary.real_periodic_angles(period).unwrap().len())
+            .collect();
+        assert_eq!(counts, vec![1, 2, 2, 4, 6, 10, 18, 32, 56, 102]);
+    }
+}
@@ -0,0 +1,129 @@
+use super::{ExactUnitNumber, LaminationAlgebra, Leaf};
+
+// Why a leaf collection can't be part of a σ_2-invariant lamination. Indices refer to the checked
+// slice.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Violation {
+    Crossing(usize, usize),
+    MissingImage(usize),
+    MissingSibling(usize),
+    ForbiddenIdentification(usize),
+}
+
+impl<T: ExactUnitNumber> LaminationAlgebra<T> {
+    // Every violation of the conditions for the leaves to extend to a quadratic invariant
+    // lamination: no two leaves cross, the image and the sibling of each leaf are leaves, and no
+    // leaf joins points whose orbits never meet but have different preperiods or periods.
+    // Degenerate leaves are points and only take part in the crossing check. An empty report
+    // means the collection is realizable.
+    pub fn realizability_violations(&self, leaves: &[Leaf<T>]) -> Result<Vec<Violation>, String> {
+        if self.base != 2 {
+            return Err(format!("realizability checks need base 2, not {}", self.base));
+        }
+
+        let sorted: Vec<Leaf<T>> = leaves
+            .iter()
+            .map(|(a, b)| self.sorted_chord(a.clone(), b.clone()))
+            .collect();
+        let is_leaf = |(a, b): Leaf<T>| sorted.contains(&self.sorted_chord(a, b));
+
+        let mut violations: Vec<Violation> = self
+            .crossings(&sorted)
+            .into_iter()
+            .map(|(i, j)| Violation::Crossing(i, j))
+            .collect();
+
+        for (index, (a, b)) in sorted.iter().enumerate() {
+            if a == b {
+                continue;
+            }
+            let image = (self.map(a), self.map(b));
+            // Critical leaves collapse to a point and are their own siblings.
+            if image.0 == image.1 {
+                continue;
+            }
+            if !is_leaf(image.clone()) {
+                violations.push(Violation::MissingImage(index));
+            }
+            if !is_leaf((self.sibling(a, &image.0)?, self.sibling(b, &image.1)?)) {
+                violations.push(Violation::MissingSibling(index));
+            }
+            if self.forbidden_identification(a, b) {
+                violations.push(Violation::ForbiddenIdentification(index));
+            }
+        }
+        Ok(violations)
+    }
+
+    fn sibling(&self, x: &T, image: &T) -> Result<T, String> {
+        self.preimages(image)?
+            .into_iter()
+            .find(|preimage| preimage != x)
+            .ok_or_else(|| format!("{} has no sibling", self.format(x)))
+    }
+
+    // Orbits that meet do so within the longer preperiod, after which both points are periodic.
+    fn forbidden_identification(&self, a: &T, b: &T) -> bool {
+        let (a_preperiod, a_period) = self.cycle(a, usize::MAX).unwrap();
+        let (b_preperiod, b_period) = self.cycle(b, usize::MAX).unwrap();
+        if a_period != b_period {
+            return true;
+        }
+
+        let (mut x, mut y) = (a.clone(), b.clone());
+        for _ in 0..=a_preperiod.max(b_preperiod) {
+            if x == y {
+                return false;
+            }
+            x = self.map(&x);
+            y = self.map(&y);
+        }
+        a_preperiod != b_preperiod
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::DefaultAlgebra;
+    use super::super::test_support::pairs;
+    use super::Violation;
+
+    #[test]
+    fn checks_realizability() {
+        let binary = DefaultAlgebra::new(2);
+        let check = |leaves: &[(&str, &str)]| {
+            binary.realizability_violations(&pairs(&binary, leaves))
+        };
+
+        assert_eq!(check(&[("_01", "_10"), ("0_01", "1_10")]), Ok(vec![]));
+        assert_eq!(check(&[("_0", "1")]), Ok(vec![]));
+        assert_eq!(check(&[("_01", "_10")]), Ok(vec![Violation::MissingSibling(0)]));
+        assert_eq!(
+            check(&[("0_01", "1_10")]),
+            Ok(vec![Violation::MissingImage(0), Violation::MissingSibling(0)])
+        );
+        assert_eq!(
+            check(&[("_01", "_10"), ("0_01", "1_10"), ("_0", "1")]),
+            Ok(vec![Violation::Crossing(0, 2), Violation::Crossing(1, 2)])
+        );
+        assert_eq!(
+            check(&[("_10", "_01"), ("1_10", "0_01"), ("1", "_0")]),
+            Ok(vec![Violation::Crossing(0, 2), Violation::Crossing(1, 2)])
+        );
+        assert_eq!(check(&[("_10", "_01"), ("1_10", "0_01")]), Ok(vec![]));
+        assert_eq!(
+            check(&[("_01", "0_01")]),
+            Ok(vec![
+                Violation::MissingImage(0),
+                Violation::MissingSibling(0),
+                Violation::ForbiddenIdentification(0),
+            ])
+        );
+
+        let ternary = DefaultAlgebra::new(3);
+        assert_eq!(
+            ternary.realizability_violations(&[]),
+            Err("realizability checks need base 2, not 3".to_string())
+        );
+    }
+}
@@ -0,0 +1,100 @@
+use super::{LaminationAlgebra, UnitNumber};
+use std::f64::consts::PI;
+use std::fmt::Write;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SvgOptions {
+    pub size: f64,
+    pub circle_stroke: f64,
+    pub leaf_stroke: f64,
+    pub max_generation: Option<usize>,
+}
+
+impl Default for SvgOptions {
+    fn default() -> Self {
+        SvgOptions {
+            size: 800.0,
+            circle_stroke: 1.0,
+            leaf_stroke: 1.0,
+            max_generation: None,
+        }
+    }
+}
+
+impl<T: UnitNumber> LaminationAlgebra<T> {
+    // Draws each leaf as a straight chord of a circle of diameter `size`, with angle 0 on the
+    // right and angles increasing counterclockwise. Leaves are tagged with a generation (pullback
+    // depth, period, ...) and those past `max_generation` are left out.
+    pub fn render_svg(&self, leaves: &[(usize, (T, T))], options: &SvgOptions) -> String {
+        let radius = options.size / 2.0;
+        let position = |x: &T| {
+            let angle = 2.0 * PI * x.to_float(self.base);
+            (radius * (1.0 + angle.cos()), radius * (1.0 - angle.sin()))
+        };
+
+        let mut svg = String::new();
+        writeln!(
+            svg,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"-10 -10 {0} {0}\">",
+            options.size + 20.0
+        )
+        .unwrap();
+        writeln!(
+            svg,
+            "  <circle cx=\"{0}\" cy=\"{0}\" r=\"{0}\" fill=\"none\" stroke=\"black\" \
+             stroke-width=\"{1}\"/>",
+            radius, options.circle_stroke
+        )
+        .unwrap();
+        for (generation, (a, b)) in leaves {
+            if options.max_generation.is_some_and(|max| *generation > max) {
+                continue;
+            }
+            let ((x1, y1), (x2, y2)) = (position(a), position(b));
+            writeln!(
+                svg,
+                "  <line x1=\"{:.3}\" y1=\"{:.3}\" x2=\"{:.3}\" y2=\"{:.3}\" stroke=\"black\" \
+                 stroke-width=\"{}\"/>",
+                x1, y1, x2, y2, options.leaf_stroke
+            )
+            .unwrap();
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::DefaultAlgebra;
+    use super::super::test_support::pair;
+    use super::*;
+
+    #[test]
+    fn renders_chords() {
+        let binary = DefaultAlgebra::new(2);
+        let leaves = vec![(1, pair(&binary, "_", "1")), (2, pair(&binary, "01", "11"))];
+        let options = SvgOptions {
+            size: 100.0,
+            circle_stroke: 2.0,
+            leaf_stroke: 0.5,
+            max_generation: None,
+        };
+
+        assert_eq!(
+            binary.render_svg(&leaves, &options),
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"-10 -10 120 120\">
+  <circle cx=\"50\" cy=\"50\" r=\"50\" fill=\"none\" stroke=\"black\" stroke-width=\"2\"/>
+  <line x1=\"100.000\" y1=\"50.000\" x2=\"0.000\" y2=\"50.000\" stroke=\"black\" stroke-width=\"0.5\"/>
+  <line x1=\"50.000\" y1=\"0.000\" x2=\"50.000\" y2=\"100.000\" stroke=\"black\" stroke-width=\"0.5\"/>
+</svg>
+"
+        );
+
+        let shallow = SvgOptions {
+            max_generation: Some(1),
+            ..options
+        };
+        assert_eq!(binary.render_svg(&leaves, &shallow).matches("<line").count(), 1);
+    }
+}
@@ -0,0 +1,261 @@
+use super::{DefaultAlgebra, ExactUnitNumber, InBase, LaminationAlgebra, UnitFraction};
+use num::rational::Ratio;
+use num::Integer;
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+
+impl DefaultAlgebra {
+    // Points of exact period `period`, in circular order.
+    pub fn periodic_points(&self, period: usize) -> Result<Vec<UnitFraction>, String> {
+        self.check_period(period)?;
+        let denominator = self.powers().repeating_denominator(period);
+        (0..denominator)
+            .map(|numerator| {
+                let rational = Ratio::new(numerator, denominator);
+                UnitFraction::try_from(InBase::new(self.base, rational))
+            })
+            .filter(|x| x.as_ref().map_or(true, |x| self.period(x) == Some(period)))
+            .collect()
+    }
+
+    // Every rotation set with rotation number p/q for q <= max_denominator, as unions of at most
+    // base - 1 cycles on which the map preserves cyclic order.
+    pub fn rotation_sets(
+        &self,
+        max_denominator: usize,
+    ) -> Result<impl Iterator<Item = (Ratio<u128>, Vec<UnitFraction>)> + '_, String> {
+        self.check_period(max_denominator)?;
+        Ok((1..=max_denominator).flat_map(move |denominator| {
+            (0..denominator)
+                .filter(move |numerator| numerator.gcd(&denominator) == 1)
+                .flat_map(move |numerator| {
+                    let rotation_number = Ratio::new(numerator as u128, denominator as u128);
+                    self.rotation_sets_with(rotation_number)
+                        .into_iter()
+                        .map(move |set| (rotation_number, set))
+                })
+        }))
+    }
+
+    // The rotation number of a periodic angle's orbit, if the map preserves its cyclic order.
+    pub fn rotation_number(&self, angle: &UnitFraction) -> Option<Ratio<u128>> {
+        self.period(angle)?;
+        let cycle = self.sorted_cycle(self.orbit(angle));
+        let shift = self.rotation_shift(&cycle)?;
+        Some(Ratio::new(shift as u128, cycle.len() as u128))
+    }
+
+    pub fn rotation_minor(
+        &self,
+        rotation_number: Ratio<u128>,
+    ) -> Result<(UnitFraction, UnitFraction), String> {
+        if self.base != 2 {
+            return Err(format!("rotation minors need base 2, not {}", self.base));
+        }
+        if rotation_number >= Ratio::from_integer(1) {
+            return Err(format!("rotation number {} is not less than 1", rotation_number));
+        }
+        self.check_period(*rotation_number.denom() as usize)?;
+
+        let rotation_set = self
+            .rotation_sets_with(rotation_number)
+            .into_iter()
+            .next()
+            .ok_or_else(|| format!("no rotation set has rotation number {}", rotation_number))?;
+        self.minor_leaf(&rotation_set)
+    }
+
+    fn check_period(&self, period: usize) -> Result<(), String> {
+        let power = u32::try_from(period)
+            .ok()
+            .and_then(|period| (self.base as u128).checked_pow(period));
+        match power {
+            Some(_) => Ok(()),
+            None => Err(format!(
+                "period {} points in base {} do not fit in u128",
+                period, self.base
+            )),
+        }
+    }
+
+    // Goldberg's construction. A rotation set of k cycles with rotation number p/q has n = kq
+    // points x_0 < ... < x_{n-1}, and the map sends x_i to x_{i + kp}. Leading digits are
+    // nondecreasing in i, so the set is determined by how many points lead with each digit, and
+    // the digits of x_i are the leading digits of x_i, x_{i + kp}, x_{i + 2kp}, ... Every such
+    // deployment whose points come out strictly increasing is a rotation set, and vice versa.
+    fn rotation_sets_with(&self, rotation_number: Ratio<u128>) -> Vec<Vec<UnitFraction>> {
+        let (numerator, denominator) = (
+            *rotation_number.numer() as usize,
+            *rotation_number.denom() as usize,
+        );
+        let base = self.base as usize;
+
+        let mut sets = vec![];
+        for cycles in 1..base {
+            let len = cycles * denominator;
+            let shift = cycles * numerator;
+            // thresholds[j] is the index of the first point leading with a digit above j.
+            let mut thresholds = vec![0; base - 1];
+            loop {
+                let leading = |index: usize| thresholds.iter().filter(|&&t| t <= index).count();
+                let points: Vec<UnitFraction> = (0..len)
+                    .map(|index| {
+                        let word: Vec<u16> = (0..denominator)
+                            .map(|step| leading((index + step * shift) % len) as u16)
+                            .collect();
+                        UnitFraction::from_digits(self.base, &[], &word)
+                    })
+                    .collect();
+                let is_rotation_set = points.iter().enumerate().all(|(index, x)| {
+                    self.leading_digit(x) == leading(index)
+                        && (index == 0 || self.cmp(&points[index - 1], x) == Ordering::Less)
+                });
+                if is_rotation_set {
+                    sets.push(points);
+                }
+
+                match thresholds.iter().rposition(|&t| t < len) {
+                    Some(position) => {
+                        let next = thresholds[position] + 1;
+                        for t in &mut thresholds[position..] {
+                            *t = next;
+                        }
+                    }
+                    None => break,
+                }
+            }
+        }
+        sets
+    }
+
+    fn leading_digit(&self, x: &UnitFraction) -> usize {
+        (self.to_rational(x) * self.base as u128).to_integer() as usize
+    }
+
+    fn sorted_cycle(&self, mut points: Vec<UnitFraction>) -> Vec<UnitFraction> {
+        points.sort_by(|a, b| self.cmp(a, b));
+        points
+    }
+
+    // The k such that the map sends the i-th point to the (i + k)-th, if it preserves cyclic
+    // order on the sorted points.
+    fn rotation_shift(&self, sorted: &[UnitFraction]) -> Option<usize> {
+        let image = self.map(&sorted[0]);
+        let shift = sorted.iter().position(|x| *x == image)?;
+        let preserves_order = sorted
+            .iter()
+            .enumerate()
+            .all(|(index, x)| self.map(x) == sorted[(index + shift) % sorted.len()]);
+        if preserves_order {
+            Some(shift)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: ExactUnitNumber> LaminationAlgebra<T> {
+    // The shortest side of the rotation set's polygon, or a degenerate leaf for a fixed point.
+    pub fn minor_leaf(&self, rotation_set: &[T]) -> Result<(T, T), String> {
+        match rotation_set {
+            [point] => Ok((point.clone(), point.clone())),
+            _ => self.wake(rotation_set),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_support::{pair, parse_all};
+    use super::*;
+
+    #[test]
+    fn finds_periodic_points() {
+        let binary = DefaultAlgebra::new(2);
+        assert_eq!(
+            binary.periodic_points(3).unwrap(),
+            binary.parse_all(vec!["_001", "_010", "_011", "_100", "_101", "_110"]).unwrap()
+        );
+        assert_eq!(binary.periodic_points(1).unwrap(), binary.parse_all(vec!["_"]).unwrap());
+        assert!(binary.periodic_points(128).is_err());
+    }
+
+    #[test]
+    fn enumerates_rotation_sets() {
+        let binary = DefaultAlgebra::new(2);
+        let sets: Vec<_> = binary.rotation_sets(3).unwrap().collect();
+        assert_eq!(
+            sets,
+            vec![
+                (Ratio::new(0, 1), parse_all(&binary, &["_"])),
+                (Ratio::new(1, 2), parse_all(&binary, &["_01", "_10"])),
+                (Ratio::new(1, 3), parse_all(&binary, &["_001", "_010", "_100"])),
+                (Ratio::new(2, 3), parse_all(&binary, &["_011", "_101", "_110"])),
+            ]
+        );
+        assert_eq!(binary.rotation_sets(8).unwrap().count(), 1 + 1 + 2 + 2 + 4 + 2 + 6 + 4);
+
+        let ternary = DefaultAlgebra::new(3);
+        let halves: Vec<_> = ternary
+            .rotation_sets(2)
+            .unwrap()
+            .filter(|(rotation_number, _)| *rotation_number == Ratio::new(1, 2))
+            .map(|(_, set)| set)
+            .collect();
+        assert_eq!(
+            halves,
+            vec![
+                parse_all(&ternary, &["_12", "_21"]),
+                parse_all(&ternary, &["_02", "_20"]),
+                parse_all(&ternary, &["_01", "_10"]),
+                parse_all(&ternary, &["_02", "_12", "_20", "_21"]),
+                parse_all(&ternary, &["_01", "_02", "_10", "_20"]),
+            ]
+        );
+    }
+
+    #[test]
+    fn finds_rotation_numbers() {
+        let binary = DefaultAlgebra::new(2);
+        let rotation_number = |s: &str| binary.rotation_number(&binary.parse(s).unwrap());
+
+        assert_eq!(rotation_number("_"), Some(Ratio::new(0, 1)));
+        assert_eq!(rotation_number("_010"), Some(Ratio::new(1, 3)));
+        assert_eq!(rotation_number("_01001"), Some(Ratio::new(2, 5)));
+        assert_eq!(rotation_number("_011"), Some(Ratio::new(2, 3)));
+        assert_eq!(rotation_number("_0011"), None);
+        assert_eq!(rotation_number("0_01"), None);
+    }
+
+    #[test]
+    fn finds_minor_leaves() {
+        let binary = DefaultAlgebra::new(2);
+
+        assert_eq!(binary.rotation_minor(Ratio::new(0, 1)), Ok(pair(&binary, "_", "_")));
+        assert_eq!(binary.rotation_minor(Ratio::new(1, 2)), Ok(pair(&binary, "_01", "_10")));
+        assert_eq!(binary.rotation_minor(Ratio::new(1, 3)), Ok(pair(&binary, "_001", "_010")));
+        assert_eq!(binary.rotation_minor(Ratio::new(2, 5)), Ok(pair(&binary, "_01001", "_01010")));
+        assert_eq!(
+            binary.rotation_minor(Ratio::new(3, 2)),
+            Err("rotation number 3/2 is not less than 1".to_string())
+        );
+        assert_eq!(
+            binary.rotation_minor(Ratio::new(1, 130)),
+            Err("period 130 points in base 2 do not fit in u128".to_string())
+        );
+        let (lower, upper) = binary.rotation_minor(Ratio::new(3, 100)).unwrap();
+        assert_eq!(binary.period(&lower), Some(100));
+        assert_eq!(binary.rotation_number(&upper), Some(Ratio::new(3, 100)));
+
+        let ternary = DefaultAlgebra::new(3);
+        let set = ternary.parse_all(vec!["_01", "_02", "_10", "_20"]).unwrap();
+        assert_eq!(
+            ternary.minor_leaf(&set),
+            Ok((ternary.parse("_01").unwrap(), ternary.parse("_02").unwrap()))
+        );
+        assert_eq!(
+            ternary.rotation_minor(Ratio::new(1, 2)),
+            Err("rotation minors need base 2, not 3".to_string())
+        );
+    }
+}
@@ -0,0 +1,16 @@
+use super::{LaminationAlgebra, Leaf, UnitNumber};
+
+pub fn parse_all<T: UnitNumber>(algebra: &LaminationAlgebra<T>, strings: &[&str]) -> Vec<T> {
+    algebra.parse_all(strings.iter().cloned()).unwrap()
+}
+
+pub fn pair<T: UnitNumber>(algebra: &LaminationAlgebra<T>, a: &str, b: &str) -> Leaf<T> {
+    (algebra.parse(a).unwrap(), algebra.parse(b).unwrap())
+}
+
+pub fn pairs<T: UnitNumber>(
+    algebra: &LaminationAlgebra<T>,
+    pairs: &[(&str, &str)],
+) -> Vec<Leaf<T>> {
+    pairs.iter().map(|(a, b)| pair(algebra, a, b)).collect()
+}
@@ -0,0 +1,138 @@
+use super::{LaminationAlgebra, UnitNumber};
+use std::f64::consts::PI;
+use std::fmt::Write;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TikzOptions {
+    pub scale: f64,
+    pub geodesics: bool,
+    pub labels: bool,
+}
+
+impl Default for TikzOptions {
+    fn default() -> Self {
+        TikzOptions {
+            scale: 3.0,
+            geodesics: false,
+            labels: false,
+        }
+    }
+}
+
+impl<T: UnitNumber> LaminationAlgebra<T> {
+    // A tikzpicture of the unit circle with each leaf drawn as a chord, or as the hyperbolic
+    // geodesic between its endpoints. Labels give each endpoint's digits outside the circle.
+    pub fn lamination_tikz(&self, leaves: &[(T, T)], options: &TikzOptions) -> String {
+        let mut tikz = String::new();
+        writeln!(tikz, "\\begin{{tikzpicture}}[scale={}]", options.scale).unwrap();
+        tikz.push_str("  \\draw (0,0) circle (1);\n");
+
+        for (a, b) in leaves {
+            let (alpha, beta) = (self.radians(a), self.radians(b));
+            let start = point(alpha);
+            // The geodesic is an arc of the circle through both endpoints that meets the unit
+            // circle at right angles. Diameters and degenerate leaves stay straight.
+            let separation = (beta - alpha).rem_euclid(2.0 * PI);
+            let half = separation.min(2.0 * PI - separation) / 2.0;
+            if !options.geodesics || (half - PI / 2.0).abs() < 1e-9 || half < 1e-9 {
+                writeln!(tikz, "  \\draw {} -- {};", start, point(beta)).unwrap();
+                continue;
+            }
+
+            let middle = if separation <= PI {
+                alpha + separation / 2.0
+            } else {
+                beta + (2.0 * PI - separation) / 2.0
+            };
+            let (center_x, center_y) = (middle.cos() / half.cos(), middle.sin() / half.cos());
+            let start_angle = (alpha.sin() - center_y).atan2(alpha.cos() - center_x);
+            let end_angle = (beta.sin() - center_y).atan2(beta.cos() - center_x);
+            let sweep = (end_angle - start_angle + PI).rem_euclid(2.0 * PI) - PI;
+            writeln!(
+                tikz,
+                "  \\draw {} arc[start angle={}, end angle={}, radius={}];",
+                start,
+                number(start_angle.to_degrees()),
+                number((start_angle + sweep).to_degrees()),
+                number(half.tan())
+            )
+            .unwrap();
+        }
+
+        if options.labels {
+            let mut labeled: Vec<&T> = vec![];
+            for endpoint in leaves.iter().flat_map(|(a, b)| vec![a, b]) {
+                if labeled.contains(&endpoint) {
+                    continue;
+                }
+                labeled.push(endpoint);
+                let angle = self.radians(endpoint);
+                writeln!(
+                    tikz,
+                    "  \\node[anchor={}] at {} {{\\texttt{{{}}}}};",
+                    number((angle.to_degrees() + 180.0).rem_euclid(360.0)),
+                    point(angle),
+                    self.format(endpoint).replace('_', "\\_")
+                )
+                .unwrap();
+            }
+        }
+
+        tikz.push_str("\\end{tikzpicture}\n");
+        tikz
+    }
+
+    fn radians(&self, x: &T) -> f64 {
+        2.0 * PI * x.to_float(self.base)
+    }
+}
+
+fn point(angle: f64) -> String {
+    format!("({},{})", number(angle.cos()), number(angle.sin()))
+}
+
+fn number(value: f64) -> String {
+    let value = if value.abs() < 5e-5 { 0.0 } else { value };
+    format!("{:.4}", value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::DefaultAlgebra;
+    use super::super::test_support::pair;
+    use super::*;
+
+    #[test]
+    fn exports_tikz() {
+        let binary = DefaultAlgebra::new(2);
+        let leaves = vec![pair(&binary, "_", "1"), pair(&binary, "_", "01")];
+
+        assert_eq!(
+            binary.lamination_tikz(&leaves, &TikzOptions::default()),
+            "\\begin{tikzpicture}[scale=3]
+  \\draw (0,0) circle (1);
+  \\draw (1.0000,0.0000) -- (-1.0000,0.0000);
+  \\draw (1.0000,0.0000) -- (0.0000,1.0000);
+\\end{tikzpicture}
+"
+        );
+
+        let options = TikzOptions {
+            scale: 2.0,
+            geodesics: true,
+            labels: true,
+        };
+        assert_eq!(
+            binary.lamination_tikz(&leaves, &options),
+            "\\begin{tikzpicture}[scale=2]
+  \\draw (0,0) circle (1);
+  \\draw (1.0000,0.0000) -- (-1.0000,0.0000);
+  \\draw (1.0000,0.0000) arc[start angle=-90.0000, end angle=-180.0000, radius=1.0000];
+  \\node[anchor=180.0000] at (1.0000,0.0000) {\\texttt{\\_}};
+  \\node[anchor=0.0000] at (-1.0000,0.0000) {\\texttt{1\\_}};
+  \\node[anchor=270.0000] at (0.0000,1.0000) {\\texttt{01\\_}};
+\\end{tikzpicture}
+"
+        );
+    }
+}
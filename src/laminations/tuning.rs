@@ -0,0 +1,130 @@
+use super::{DefaultAlgebra, UnitFraction, UnitNumber};
+use num::rational::Ratio;
+
+impl DefaultAlgebra {
+    // Douady tuning: replace each binary digit of the child angle with the repeating word of the
+    // lower (for 0) or upper (for 1) parent angle.
+    pub fn tune(
+        &self,
+        parent: &(UnitFraction, UnitFraction),
+        child: &UnitFraction,
+    ) -> Result<UnitFraction, String> {
+        if self.base != 2 {
+            return Err(format!("tuning needs base 2, not {}", self.base));
+        }
+        let (lower, upper) = self.parent_words(parent)?;
+
+        let (exact, mut repeating) = child.to_digits(2);
+        if repeating.is_empty() {
+            repeating.push(0);
+        }
+        let substitute = |digits: &[u16]| -> Vec<u16> {
+            digits
+                .iter()
+                .flat_map(|&digit| if digit == 0 { lower.clone() } else { upper.clone() })
+                .collect()
+        };
+        let (exact, repeating) = (substitute(&exact), substitute(&repeating));
+
+        let total_len = exact.len() + repeating.len();
+        if 2u128.checked_pow(total_len as u32).is_none() {
+            return Err(format!(
+                "tuning {} needs {} digits, more than fit in a UnitFraction",
+                self.format(child),
+                total_len
+            ));
+        }
+        Ok(UnitFraction::from_digits(2, &exact, &repeating))
+    }
+
+    pub fn satellite(
+        &self,
+        parent: &(UnitFraction, UnitFraction),
+        rotation_number: Ratio<u128>,
+    ) -> Result<(UnitFraction, UnitFraction), String> {
+        let (lower, upper) = self.rotation_minor(rotation_number)?;
+        Ok((self.tune(parent, &lower)?, self.tune(parent, &upper)?))
+    }
+
+    // The main cardioid's degenerate minor at 0 tunes by the identity. Other parents must be
+    // periodic minor leaves, given in either order.
+    fn parent_words(
+        &self,
+        parent: &(UnitFraction, UnitFraction),
+    ) -> Result<(Vec<u16>, Vec<u16>), String> {
+        let (lower, upper) = self.sorted_chord(parent.0.clone(), parent.1.clone());
+        if lower == upper && self.to_rational(&lower) == Ratio::from_integer(0) {
+            return Ok((vec![0], vec![1]));
+        }
+
+        let period = self.period(&lower);
+        if period.is_none()
+            || period != self.period(&upper)
+            || lower == upper
+            || !self.in_qml(&lower, &upper)?
+        {
+            return Err(format!(
+                "{} and {} are not a periodic minor leaf",
+                self.format(&lower),
+                self.format(&upper)
+            ));
+        }
+        Ok((lower.to_digits(2).1, upper.to_digits(2).1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_support::pair;
+    use super::*;
+
+    #[test]
+    fn tunes_angles() {
+        let binary = DefaultAlgebra::new(2);
+        let parse = |s: &str| binary.parse(s).unwrap();
+
+        let basilica = pair(&binary, "_01", "_10");
+        assert_eq!(binary.tune(&basilica, &parse("_001")), Ok(parse("_010110")));
+        let reversed = pair(&binary, "_10", "_01");
+        assert_eq!(binary.tune(&reversed, &parse("_001")), Ok(parse("_010110")));
+        assert_eq!(binary.tune(&basilica, &parse("_")), Ok(parse("_01")));
+        assert_eq!(binary.tune(&basilica, &parse("1")), Ok(parse("10_01")));
+        assert_eq!(binary.tune(&pair(&binary, "_", "_"), &parse("_011")), Ok(parse("_011")));
+
+        assert_eq!(
+            binary.tune(&pair(&binary, "_01", "_011"), &parse("_1")),
+            Err("_01 and _011 are not a periodic minor leaf".to_string())
+        );
+        assert_eq!(
+            binary.tune(&pair(&binary, "_100", "_001"), &parse("_1")),
+            Err("_001 and _100 are not a periodic minor leaf".to_string())
+        );
+    }
+
+    #[test]
+    fn finds_satellites() {
+        let binary = DefaultAlgebra::new(2);
+
+        let cardioid = pair(&binary, "_", "_");
+        assert_eq!(
+            binary.satellite(&cardioid, Ratio::new(1, 3)),
+            Ok(pair(&binary, "_001", "_010"))
+        );
+
+        let basilica = pair(&binary, "_01", "_10");
+        assert_eq!(
+            binary.satellite(&basilica, Ratio::new(1, 2)),
+            Ok(pair(&binary, "_0110", "_1001"))
+        );
+        assert_eq!(
+            binary.satellite(&basilica, Ratio::new(1, 3)),
+            Ok(pair(&binary, "_010110", "_011001"))
+        );
+
+        let airplane = pair(&binary, "_011", "_100");
+        let doubling = binary.satellite(&airplane, Ratio::new(1, 2)).unwrap();
+        assert_eq!(doubling, pair(&binary, "_011100", "_100011"));
+        assert_eq!(binary.in_qml(&doubling.0, &doubling.1), Ok(true));
+        assert_eq!(binary.is_real_angle(&doubling.0), Ok(true));
+    }
+}
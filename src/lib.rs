@@ -1,17 +1,178 @@
+// Parses an n-ary point, panicking on malformed input. A call with two literals parses once and
+// caches the point for later evaluations of that call site; other arguments are evaluated once
+// and parsed on every evaluation.
+#[macro_export]
+macro_rules! nary {
+    (@parse $base:expr, $s:expr) => {{
+        let (base, s) = ($base, $s);
+        $crate::laminations::DefaultAlgebra::new(base)
+            .parse(s)
+            .unwrap_or_else(|error| panic!("nary!({}, {:?}): {}", base, s, error))
+    }};
+    ($base:literal, $s:literal) => {{
+        static POINT: ::std::sync::OnceLock<$crate::laminations::UnitFraction> =
+            ::std::sync::OnceLock::new();
+        POINT.get_or_init(|| $crate::nary!(@parse $base, $s)).clone()
+    }};
+    ($base:expr, $s:expr) => {
+        $crate::nary!(@parse $base, $s)
+    };
+}
+
 pub mod laminations {
     use num::pow::pow;
-    use num::rational::Ratio;
+    use num::bigint::BigInt;
+    use num::rational::{BigRational, Ratio};
+    use num::{FromPrimitive, Integer, ToPrimitive};
+    use std::borrow::Cow;
     use std::cmp::{Eq, Ord, Ordering};
+    use std::collections::HashMap;
+    use std::convert::TryFrom;
+    use std::hash::Hash;
+    use std::io::{BufRead, Lines};
     use std::marker::PhantomData;
 
+    // ToPrimitive, FromPrimitive and TryFrom<f64> for a point type. Points have no integer part, so
+    // integers convert to and from 0; only the float conversions differ between types.
+    macro_rules! impl_primitive_conversions {
+        (
+            $point:ty,
+            zero: $zero:expr,
+            to_f64: |$x:ident| $to_f64:expr,
+            from_f64: |$n:ident| $from_f64:expr $(,)?
+        ) => {
+            impl num::ToPrimitive for $point {
+                fn to_i64(&self) -> Option<i64> {
+                    Some(0)
+                }
+
+                fn to_u64(&self) -> Option<u64> {
+                    Some(0)
+                }
+
+                fn to_f64(&self) -> Option<f64> {
+                    let $x = self;
+                    $to_f64
+                }
+            }
+
+            impl num::FromPrimitive for $point {
+                fn from_i64(_n: i64) -> Option<Self> {
+                    Some($zero)
+                }
+
+                fn from_u64(_n: u64) -> Option<Self> {
+                    Some($zero)
+                }
+
+                fn from_f64($n: f64) -> Option<Self> {
+                    $from_f64
+                }
+            }
+
+            impl std::convert::TryFrom<f64> for $point {
+                type Error = String;
+
+                fn try_from(value: f64) -> Result<Self, Self::Error> {
+                    <$point as num::FromPrimitive>::from_f64(value).ok_or_else(|| {
+                        format!("{} is not representable as a {}", value, stringify!($point))
+                    })
+                }
+            }
+        };
+    }
+
+    mod big_point;
+    mod binary_angle;
+    mod cached_point;
+    mod continued_fractions;
+    mod digit_buffer;
+    mod dot;
+    #[cfg(feature = "edu")]
+    mod exercises;
+    mod explain;
+    mod farey;
+    mod float_point;
+    mod interval_point;
+    mod kneading;
+    mod leaf_csv;
+    mod necklace;
+    mod orbit_table;
+    mod portrait;
+    mod qml;
+    mod rational_point;
+    mod real_slice;
+    mod realizability;
+    #[cfg(feature = "svg")]
+    mod render;
+    mod rotation_sets;
+    #[cfg(test)]
+    mod test_support;
+    mod tikz;
+    mod tuning;
+
+    pub use big_point::BigPoint;
+    pub use binary_angle::BinaryAngle;
+    pub use cached_point::CachedPoint;
+    pub use continued_fractions::{continued_fraction, convergents, stern_brocot_path};
+    pub use digit_buffer::DigitBuffer;
+    #[cfg(feature = "edu")]
+    pub use exercises::Exercise;
+    pub use explain::ParseStep;
+    pub use farey::{farey_neighbors, mediant};
+    pub use float_point::FloatPoint;
+    pub use interval_point::IntervalPoint;
+    pub use kneading::{
+        internal_address, is_admissible, kneading_entropy, kneading_from_internal_address,
+    };
+    pub use necklace::{aperiodic_words, lyndon_rotation, lyndon_words, necklace};
+    pub use portrait::OrbitPortrait;
+    pub use qml::MinorLeaf;
+    pub use rational_point::RationalPoint;
+    pub use realizability::Violation;
+    #[cfg(feature = "svg")]
+    pub use render::SvgOptions;
+    pub use tikz::TikzOptions;
+
     pub type DefaultAlgebra = LaminationAlgebra<UnitFraction>;
 
-    pub trait UnitNumber: Eq + Ord + Sized {
-        fn parse_nary(base: u8, s: &str) -> Result<Self, String>;
+    // A chord between two points. Leaves of generated laminations are usually tagged with a
+    // generation, as (usize, Leaf<T>).
+    pub type Leaf<T> = (T, T);
+
+    pub trait UnitNumber: Clone + Eq + Sized {
+        fn check_base(base: u16) -> Result<(), String> {
+            if base < 2 {
+                return Err(format!("base {} is less than 2", base));
+            }
+            Ok(())
+        }
+
+        fn parse_nary(base: u16, s: &str) -> Result<Self, String>;
+
+        fn to_rational(&self, base: u16) -> Ratio<u128>;
+
+        fn to_rational_cached(&self, powers: &Powers) -> Ratio<u128> {
+            self.to_rational(powers.base())
+        }
+
+        // The exact value for types that can outgrow Ratio<u128>, where to_rational panics.
+        fn to_big_rational(&self, base: u16) -> BigRational {
+            let rational = self.to_rational(base);
+            BigRational::new(BigInt::from(*rational.numer()), BigInt::from(*rational.denom()))
+        }
+
+        fn to_digits(&self, base: u16) -> (Vec<u16>, Vec<u16>);
 
-        fn to_rational(&self, base: u8) -> Ratio<u128>;
+        fn map(&self, base: u16) -> Self;
 
-        fn to_float(&self, base: u8) -> f64 {
+        fn preimages(&self, base: u16) -> Result<Vec<Self>, String>;
+
+        fn cmp_in_base(&self, other: &Self, base: u16) -> Ordering {
+            self.to_rational(base).cmp(&other.to_rational(base))
+        }
+
+        fn to_float(&self, base: u16) -> f64 {
             let rational = self.to_rational(base);
             let numerator: f64 = *rational.numer() as f64;
             let denominator: f64 = *rational.denom() as f64;
@@ -19,34 +180,389 @@ pub mod laminations {
         }
     }
 
+    // Point types whose map is exact, so every orbit is eventually periodic and equality finds
+    // the cycle. Orbit-based algorithms (periods, portraits, kneading, the QML) need this.
+    pub trait ExactUnitNumber: UnitNumber {}
+
+    #[derive(Clone, Debug)]
+    pub struct Powers {
+        base: u16,
+        powers: Vec<u128>,
+    }
+
+    impl Powers {
+        pub fn new(base: u16) -> Powers {
+            let mut powers = vec![1u128];
+            while let Some(power) = powers.last().unwrap().checked_mul(base as u128) {
+                powers.push(power);
+            }
+            Powers { base, powers }
+        }
+
+        pub fn base(&self) -> u16 {
+            self.base
+        }
+
+        pub fn pow(&self, exponent: usize) -> u128 {
+            match self.powers.get(exponent) {
+                Some(&power) => power,
+                None => panic!("{}^{} overflows u128", self.base, exponent),
+            }
+        }
+
+        pub fn repeating_denominator(&self, repeating_len: usize) -> u128 {
+            match repeating_len {
+                0 => 1,
+                _ => self.pow(repeating_len) - 1,
+            }
+        }
+    }
+
     pub struct LaminationAlgebra<T: UnitNumber> {
-        pub base: u8,
+        pub base: u16,
+        powers: Powers,
+        digit_separator: Option<char>,
+        trim_whitespace: bool,
+        accept_dot: bool,
         _marker: PhantomData<T>,
     }
 
     impl<T: UnitNumber> LaminationAlgebra<T> {
-        pub fn new(base: u8) -> Self {
-            Self {
+        pub fn new(base: u16) -> Self {
+            Self::try_new(base).unwrap_or_else(|error| panic!("{}", error))
+        }
+
+        pub fn try_new(base: u16) -> Result<Self, String> {
+            T::check_base(base)?;
+            Ok(Self {
                 base,
+                powers: Powers::new(base),
+                digit_separator: None,
+                trim_whitespace: false,
+                accept_dot: false,
                 _marker: PhantomData,
+            })
+        }
+
+        // Underscores, dots and digits already mean something in n-ary input, so none of them can
+        // separate digits.
+        pub fn digit_separator(mut self, separator: char) -> Result<Self, String> {
+            if separator == '_' || separator == '.' || separator.is_ascii_digit() {
+                return Err(format!("`{}` cannot be a digit separator", separator));
             }
+            self.digit_separator = Some(separator);
+            Ok(self)
+        }
+
+        pub fn trim_whitespace(mut self, trim: bool) -> Self {
+            self.trim_whitespace = trim;
+            self
+        }
+
+        pub fn accept_dot(mut self, accept: bool) -> Self {
+            self.accept_dot = accept;
+            self
         }
 
         pub fn parse(&self, s: &str) -> Result<T, String> {
-            T::parse_nary(self.base, s)
+            T::parse_nary(self.base, &self.normalize_input(s))
+        }
+
+        pub fn read_lines<R: BufRead>(&self, reader: R) -> LeafLines<'_, T, R> {
+            LeafLines {
+                algebra: self,
+                lines: reader.lines(),
+                line_number: 0,
+            }
+        }
+
+        fn normalize_input<'a>(&self, s: &'a str) -> Cow<'a, str> {
+            let s = if self.trim_whitespace { s.trim() } else { s };
+            if self.digit_separator.is_none() && !self.accept_dot {
+                return Cow::Borrowed(s);
+            }
+
+            let canonical_separator = if self.base < 10 { None } else { Some(',') };
+            let normalized = s
+                .chars()
+                .filter_map(|c| match c {
+                    '.' if self.accept_dot => Some('_'),
+                    c if Some(c) == self.digit_separator => canonical_separator,
+                    c => Some(c),
+                })
+                .collect();
+            Cow::Owned(normalized)
+        }
+
+        pub fn parse_all<'a, I>(&self, strings: I) -> Result<Vec<T>, Vec<(usize, String)>>
+        where
+            I: IntoIterator<Item = &'a str>,
+        {
+            let mut parsed = vec![];
+            let mut errors = vec![];
+
+            for (index, s) in strings.into_iter().enumerate() {
+                match self.parse(s) {
+                    Ok(x) => parsed.push(x),
+                    Err(error) => errors.push((index, error)),
+                }
+            }
+
+            if errors.is_empty() {
+                Ok(parsed)
+            } else {
+                Err(errors)
+            }
+        }
+
+        pub fn format(&self, x: &T) -> String {
+            let (exact_digits, repeating_digits) = self.format_digits(x);
+            format!(
+                "{}_{}",
+                digits_to_string(self.base, &exact_digits),
+                digits_to_string(self.base, &repeating_digits)
+            )
+        }
+
+        pub fn powers(&self) -> &Powers {
+            &self.powers
+        }
+
+        pub fn to_rational(&self, x: &T) -> Ratio<u128> {
+            x.to_rational_cached(&self.powers)
+        }
+
+        pub fn to_big_rational(&self, x: &T) -> BigRational {
+            x.to_big_rational(self.base)
+        }
+
+        pub fn format_fraction(&self, x: &T) -> String {
+            let rational = self.to_big_rational(x);
+            format!("{}/{}", rational.numer(), rational.denom())
+        }
+
+        pub fn format_digits(&self, x: &T) -> (Vec<u16>, Vec<u16>) {
+            x.to_digits(self.base)
+        }
+    }
+
+    pub struct LeafLines<'a, T: UnitNumber, R> {
+        algebra: &'a LaminationAlgebra<T>,
+        lines: Lines<R>,
+        line_number: usize,
+    }
+
+    impl<'a, T: UnitNumber, R: BufRead> Iterator for LeafLines<'a, T, R> {
+        type Item = Result<Leaf<T>, String>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            loop {
+                let line = self.lines.next()?;
+                self.line_number += 1;
+
+                let line = match line {
+                    Ok(line) => line,
+                    Err(error) => return Some(Err(format!("line {}: {}", self.line_number, error))),
+                };
+                let tokens = whitespace_tokens(&line);
+                if tokens.is_empty() {
+                    continue;
+                }
+
+                if tokens.len() != 2 {
+                    return Some(Err(format!(
+                        "line {}: expected 2 points, found {}",
+                        self.line_number,
+                        tokens.len()
+                    )));
+                }
+
+                let parse = |(offset, token): (usize, &str)| {
+                    self.algebra.parse(token).map_err(|error| {
+                        let column = line[..offset].chars().count() + 1;
+                        format!("line {}, column {}: {}", self.line_number, column, error)
+                    })
+                };
+                return Some(parse(tokens[0]).and_then(|a| Ok((a, parse(tokens[1])?))));
+            }
+        }
+    }
+
+    fn whitespace_tokens(line: &str) -> Vec<(usize, &str)> {
+        let mut tokens = vec![];
+        let mut start = None;
+
+        for (offset, c) in line.char_indices() {
+            match (start, c.is_whitespace()) {
+                (None, false) => start = Some(offset),
+                (Some(token_start), true) => {
+                    tokens.push((token_start, &line[token_start..offset]));
+                    start = None;
+                }
+                _ => {}
+            }
+        }
+        if let Some(token_start) = start {
+            tokens.push((token_start, &line[token_start..]));
+        }
+
+        tokens
+    }
+
+    impl<T: UnitNumber> LaminationAlgebra<T> {
+        pub fn map(&self, x: &T) -> T {
+            x.map(self.base)
+        }
+
+        pub fn preimages(&self, x: &T) -> Result<Vec<T>, String> {
+            x.preimages(self.base)
+        }
+
+        pub fn map_batch(&self, points: &[T]) -> Vec<T> {
+            let mut images = Vec::with_capacity(points.len());
+            self.map_batch_into(points, &mut images);
+            images
+        }
+
+        pub fn map_batch_into(&self, points: &[T], images: &mut Vec<T>) {
+            images.clear();
+            images.extend(points.iter().map(|x| x.map(self.base)));
+        }
+
+        pub fn preimages_batch(&self, points: &[T]) -> Result<Vec<T>, String> {
+            let mut preimages = Vec::with_capacity(points.len() * self.base as usize);
+            self.preimages_batch_into(points, &mut preimages)?;
+            Ok(preimages)
+        }
+
+        pub fn preimages_batch_into(
+            &self,
+            points: &[T],
+            preimages: &mut Vec<T>,
+        ) -> Result<(), String> {
+            preimages.clear();
+            for x in points {
+                preimages.extend(x.preimages(self.base)?);
+            }
+            Ok(())
+        }
+
+        pub fn cmp(&self, a: &T, b: &T) -> Ordering {
+            a.cmp_in_base(b, self.base)
+        }
+
+        // Chords that share an endpoint never cross.
+        pub fn crosses(&self, a: &Leaf<T>, b: &Leaf<T>) -> bool {
+            let shares_endpoint = a.0 == b.0 || a.0 == b.1 || a.1 == b.0 || a.1 == b.1;
+            !shares_endpoint && self.strictly_between(&b.0, a) != self.strictly_between(&b.1, a)
+        }
+
+        // The index pairs of every two leaves that cross, in order.
+        pub fn crossings(&self, leaves: &[Leaf<T>]) -> Vec<(usize, usize)> {
+            let mut pairs = vec![];
+            for (i, a) in leaves.iter().enumerate() {
+                for (j, b) in leaves.iter().enumerate().skip(i + 1) {
+                    if self.crosses(a, b) {
+                        pairs.push((i, j));
+                    }
+                }
+            }
+            pairs
+        }
+
+        fn sorted_chord(&self, a: T, b: T) -> Leaf<T> {
+            match self.cmp(&a, &b) {
+                Ordering::Greater => (b, a),
+                _ => (a, b),
+            }
+        }
+
+        // Whether `x` lies on the open counterclockwise arc from chord.0 to chord.1, which wraps
+        // through 0 when chord.0 is the larger endpoint. A point strictly between the endpoints
+        // of one arc lies outside the other, so crossing tests hold in either orientation.
+        fn strictly_between(&self, x: &T, chord: &Leaf<T>) -> bool {
+            let after_start = self.cmp(&chord.0, x) == Ordering::Less;
+            let before_end = self.cmp(x, &chord.1) == Ordering::Less;
+            match self.cmp(&chord.0, &chord.1) {
+                Ordering::Greater => after_start || before_end,
+                _ => after_start && before_end,
+            }
+        }
+
+        // The orbit of `x` up to its first repeated point, or None if that takes more than
+        // `max_len` points (approximate types may never repeat exactly).
+        pub fn orbit_bounded(&self, x: &T, max_len: usize) -> Option<Vec<T>> {
+            let (preperiod, period) = self.cycle(x, max_len)?;
+            let mut orbit = Vec::with_capacity(preperiod + period);
+            orbit.push(x.clone());
+            while orbit.len() < preperiod + period {
+                orbit.push(self.map(orbit.last().unwrap()));
+            }
+            Some(orbit)
+        }
+
+        // Brent's cycle detection, which needs only equality and keeps two points alive. Returns
+        // the preperiod and period, or None once the orbit is known to exceed `max_len` points.
+        fn cycle(&self, x: &T, max_len: usize) -> Option<(usize, usize)> {
+            let (mut power, mut period) = (1, 1);
+            let mut tortoise = x.clone();
+            let mut hare = self.map(x);
+            while tortoise != hare {
+                if power == period {
+                    tortoise = hare.clone();
+                    power *= 2;
+                    period = 0;
+                }
+                hare = self.map(&hare);
+                period += 1;
+                if period > max_len {
+                    return None;
+                }
+            }
+
+            let mut tortoise = x.clone();
+            let mut hare = x.clone();
+            for _ in 0..period {
+                hare = self.map(&hare);
+            }
+            let mut preperiod = 0;
+            while tortoise != hare {
+                if preperiod + period >= max_len {
+                    return None;
+                }
+                tortoise = self.map(&tortoise);
+                hare = self.map(&hare);
+                preperiod += 1;
+            }
+            Some((preperiod, period))
+        }
+    }
+
+    impl<T: ExactUnitNumber> LaminationAlgebra<T> {
+        pub fn orbit(&self, x: &T) -> Vec<T> {
+            self.orbit_bounded(x, usize::MAX).unwrap()
+        }
+
+        pub fn period(&self, x: &T) -> Option<usize> {
+            match self.cycle(x, usize::MAX).unwrap() {
+                (0, period) => Some(period),
+                _ => None,
+            }
         }
     }
 
-    #[derive(Debug, PartialEq)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
     pub struct UnitFraction {
-        pub exact_num: u128,
-        pub exact_len: u8,
-        pub repeating_num: u128,
-        pub repeating_len: u8,
+        exact_num: u128,
+        exact_len: u8,
+        repeating_num: u128,
+        repeating_len: u8,
     }
     
     impl UnitFraction {
-        pub fn new(exact_num: u128, exact_len: u8, repeating_num: u128, repeating_len: u8) -> UnitFraction {
+        // Stores the fields as given; callers must already be in canonical form (see
+        // reduce_digits), otherwise equality and hashing stop matching the point's value.
+        fn new(exact_num: u128, exact_len: u8, repeating_num: u128, repeating_len: u8) -> UnitFraction {
             UnitFraction {
                 exact_num,
                 exact_len,
@@ -55,21 +571,139 @@ pub mod laminations {
             }
         }
 
+        pub fn from_digits(base: u16, exact: &[u16], repeating: &[u16]) -> UnitFraction {
+            UnitFraction::try_from_digits(base, exact, repeating)
+                .unwrap_or_else(|error| panic!("{}", error))
+        }
+
+        pub fn try_from_digits(
+            base: u16,
+            exact: &[u16],
+            repeating: &[u16],
+        ) -> Result<UnitFraction, String> {
+            let (exact_digits, repeating_digits) = reduce_digits(base, exact, repeating)?;
+            let total_len = exact_digits.len() + repeating_digits.len();
+            let too_long = || format!("{} digits in base {} do not fit in u128", total_len, base);
+
+            // The denominator (base^r - 1) * base^e must fit, not just the two numerators.
+            let fits = (base as u128).checked_pow(total_len as u32).is_some();
+            if total_len > u8::MAX as usize || !fits {
+                return Err(too_long());
+            }
+
+            Ok(UnitFraction::new(
+                value_from_digits(base, &exact_digits).ok_or_else(too_long)?,
+                exact_digits.len() as u8,
+                value_from_digits(base, &repeating_digits).ok_or_else(too_long)?,
+                repeating_digits.len() as u8,
+            ))
+        }
+
+        pub fn normalized(
+            base: u16,
+            exact_num: u128,
+            exact_len: u8,
+            repeating_num: u128,
+            repeating_len: u8,
+        ) -> Result<UnitFraction, String> {
+            for &(num, len) in &[(exact_num, exact_len), (repeating_num, repeating_len)] {
+                // Any u128 fits once base^len overflows.
+                if (base as u128).checked_pow(len as u32).is_some_and(|limit| num >= limit) {
+                    return Err(format!("{} does not fit in {} digits in base {}", num, len, base));
+                }
+            }
+            UnitFraction::try_from_digits(
+                base,
+                &digits_from_value(base, exact_num, exact_len),
+                &digits_from_value(base, repeating_num, repeating_len),
+            )
+        }
+
+        pub fn exact_len(&self) -> u8 {
+            self.exact_len
+        }
+
+        pub fn repeating_len(&self) -> u8 {
+            self.repeating_len
+        }
+
+        fn to_small_rational(&self, base: u16) -> Option<Ratio<u128>> {
+            let base = base as u64;
+            base.checked_pow(self.exact_len as u32 + self.repeating_len as u32)?;
+
+            let repeating_denominator = match self.repeating_len {
+                0 => 1,
+                repeating_len => base.pow(repeating_len as u32) - 1,
+            };
+            let denominator = repeating_denominator * base.pow(self.exact_len as u32);
+            let numerator =
+                repeating_denominator * self.exact_num as u64 + self.repeating_num as u64;
+
+            let rational = Ratio::new(numerator, denominator);
+            Some(Ratio::new_raw(*rational.numer() as u128, *rational.denom() as u128))
+        }
+    }
+
+    pub fn reduce_digits(
+        base: u16,
+        exact_digits: &[u16],
+        repeating_digits: &[u16],
+    ) -> Result<(DigitBuffer, DigitBuffer), String> {
+        if let Some(digit) = exact_digits.iter().chain(repeating_digits).find(|&&d| d >= base) {
+            return Err(format!("{} is not a digit in base {}", digit, base));
+        }
+
+        let mut exact: DigitBuffer = exact_digits.iter().copied().collect();
+        let period = minimal_period(repeating_digits);
+        let mut repeating: DigitBuffer = repeating_digits[..period].iter().copied().collect();
+
+        if repeating.len() == 1 && repeating[0] == base - 1 {
+            repeating.truncate(0);
+            for digit in exact.iter_mut().rev() {
+                if *digit < base - 1 {
+                    *digit += 1;
+                    break;
+                }
+                *digit = 0;
+            }
+        } else if repeating.len() == 1 && repeating[0] == 0 {
+            repeating.truncate(0);
+        }
+
+        if repeating.is_empty() {
+            while exact.last() == Some(&0) {
+                exact.pop();
+            }
+        } else {
+            while !exact.is_empty() && exact.last() == repeating.last() {
+                exact.pop();
+                repeating.rotate_right(1);
+            }
+        }
+
+        Ok((exact, repeating))
+    }
+
+    fn minimal_period(digits: &[u16]) -> usize {
+        (1..digits.len())
+            .filter(|&period| digits.len().is_multiple_of(period))
+            .find(|&period| (period..digits.len()).all(|i| digits[i] == digits[i - period]))
+            .unwrap_or(digits.len())
     }
 
     impl UnitNumber for UnitFraction {
-        fn parse_nary(base: u8, s: &str) -> Result<Self, String> {
+        fn parse_nary(base: u16, s: &str) -> Result<Self, String> {
             let (exact_digits, repeating_digits) = parse_digit_parts(base, s)?;
 
-            let exact_num = value_from_digits(base, &exact_digits);
-            let exact_len = exact_digits.len() as u8;
-            let repeating_num = value_from_digits(base, &repeating_digits);
-            let repeating_len = repeating_digits.len() as u8;
-
-            Ok(UnitFraction::new(exact_num, exact_len, repeating_num, repeating_len))
+            UnitFraction::try_from_digits(base, &exact_digits, &repeating_digits)
+                .map_err(|error| format!("{}: {}", s, error))
         }
 
-        fn to_rational(&self, base: u8) -> Ratio<u128> {
+        fn to_rational(&self, base: u16) -> Ratio<u128> {
+            if let Some(rational) = self.to_small_rational(base) {
+                return rational;
+            }
+
             let get_repeating_denominator = || -> u128 {
                 let result = pow(base as u128, self.repeating_len as usize) - 1;
                 if result == 0 {
@@ -84,63 +718,226 @@ pub mod laminations {
     
             Ratio::new(numerator, denominator)
         }
+
+        fn to_rational_cached(&self, powers: &Powers) -> Ratio<u128> {
+            let repeating_denominator = powers.repeating_denominator(self.repeating_len as usize);
+            let denominator = repeating_denominator * powers.pow(self.exact_len as usize);
+            let numerator = repeating_denominator * self.exact_num + self.repeating_num;
+
+            Ratio::new(numerator, denominator)
+        }
+
+        fn to_digits(&self, base: u16) -> (Vec<u16>, Vec<u16>) {
+            (
+                digits_from_value(base, self.exact_num, self.exact_len).into(),
+                digits_from_value(base, self.repeating_num, self.repeating_len).into(),
+            )
+        }
+
+        fn map(&self, base: u16) -> Self {
+            if self.exact_len > 0 {
+                let exact_len = self.exact_len - 1;
+                let exact_num = self.exact_num % pow(base as u128, exact_len as usize);
+                return UnitFraction::new(
+                    exact_num,
+                    exact_len,
+                    self.repeating_num,
+                    self.repeating_len,
+                );
+            }
+            if self.repeating_len == 0 {
+                return self.clone();
+            }
+
+            let tail_size = pow(base as u128, (self.repeating_len - 1) as usize);
+            let leading_digit = self.repeating_num / tail_size;
+            let repeating_num = (self.repeating_num % tail_size) * base as u128 + leading_digit;
+            UnitFraction::new(0, 0, repeating_num, self.repeating_len)
+        }
+
+        fn preimages(&self, base: u16) -> Result<Vec<Self>, String> {
+            let mut exact = DigitBuffer::zeros(self.exact_len as usize + 1);
+            exact[1..].copy_from_slice(&digits_from_value(base, self.exact_num, self.exact_len));
+            let repeating = digits_from_value(base, self.repeating_num, self.repeating_len);
+
+            (0..base)
+                .map(|digit| {
+                    exact[0] = digit;
+                    UnitFraction::try_from_digits(base, &exact, &repeating)
+                })
+                .collect()
+        }
+    }
+
+    impl ExactUnitNumber for UnitFraction {}
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub struct InBase<V> {
+        pub base: u16,
+        pub value: V,
+    }
+
+    impl<V> InBase<V> {
+        pub fn new(base: u16, value: V) -> InBase<V> {
+            InBase { base, value }
+        }
+    }
+
+    impl TryFrom<InBase<Ratio<u128>>> for UnitFraction {
+        type Error = String;
+
+        fn try_from(rational: InBase<Ratio<u128>>) -> Result<Self, Self::Error> {
+            let base = rational.base;
+            let (exact_digits, repeating_digits) = digits_from_rational(base, &rational.value);
+            let total_len = exact_digits.len() + repeating_digits.len();
+
+            let fits = (base as u128).checked_pow(total_len as u32).is_some();
+            if total_len > u8::MAX as usize || !fits {
+                return Err(format!(
+                    "{} needs {} digits in base {}, more than fit in a UnitFraction",
+                    rational.value, total_len, base
+                ));
+            }
+
+            Ok(UnitFraction::from_digits(base, &exact_digits, &repeating_digits))
+        }
     }
 
-    impl Eq for UnitFraction {}
+    impl TryFrom<InBase<f64>> for UnitFraction {
+        type Error = String;
+
+        fn try_from(float: InBase<f64>) -> Result<Self, Self::Error> {
+            if !float.value.is_finite() {
+                return Err(format!("{} is not finite", float.value));
+            }
+            let rational = Ratio::from_f64(float.value.rem_euclid(1.0))
+                .ok_or_else(|| format!("{} has no Ratio<u128> approximation", float.value))?;
+            UnitFraction::try_from(InBase::new(float.base, rational))
+        }
+    }
 
-    impl Ord for UnitFraction {
-        fn cmp(&self, other: &Self) -> Ordering {
-            self.to_rational(2).cmp(&other.to_rational(2))
+    impl From<InBase<UnitFraction>> for Ratio<u128> {
+        fn from(fraction: InBase<UnitFraction>) -> Self {
+            fraction.value.to_rational(fraction.base)
         }
     }
 
-    impl PartialOrd for UnitFraction {
-        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-            Some(self.cmp(other))
+    impl From<InBase<UnitFraction>> for f64 {
+        fn from(fraction: InBase<UnitFraction>) -> Self {
+            fraction.value.to_float(fraction.base)
         }
     }
 
-    pub fn parse_digit_parts(base: u8, s: &str) -> Result<(Vec<u8>, Vec<u8>), String> {
-        let parts: Vec<&str> = s.split('_').collect();
+    // Floats carry no base, so they convert to binary fractions.
+    impl_primitive_conversions!(
+        InBase<UnitFraction>,
+        zero: InBase::new(2, UnitFraction::new(0, 0, 0, 0)),
+        to_f64: |x| Some(x.value.to_float(x.base)),
+        from_f64: |n| UnitFraction::try_from(InBase::new(2, n)).ok().map(|x| InBase::new(2, x)),
+    );
+
+    pub fn parse_digit_parts(base: u16, s: &str) -> Result<(DigitBuffer, DigitBuffer), String> {
+        let (exact_part, repeating_part) = split_digit_parts(s)?;
+
+        let parse_digits = |digits: &str| -> Result<DigitBuffer, String> {
+            let mut parsed = DigitBuffer::new();
+            for_each_digit(base, s, digits, |digit| parsed.push(digit))?;
+            Ok(parsed)
+        };
+
+        Ok((parse_digits(exact_part)?, parse_digits(repeating_part)?))
+    }
+
+    fn split_digit_parts(s: &str) -> Result<(&str, &str), String> {
+        let (exact_part, repeating_part) = s.split_once('_').unwrap_or((s, ""));
 
-        if parts.len() > 2 {
+        if repeating_part.contains('_') {
             return Err(format!("`{}` contains more than one underscore", s));
         }
 
-        let digit_splitter = if base < 10 { "" } else { "," };
+        Ok((exact_part, repeating_part))
+    }
 
-        let parse_digits = |digits: &str| -> Result<Vec<u8>, String> {
-            digits
-                .split(digit_splitter)
-                .filter(|digit| digit.len() > 0)
-                .map(|digit: &str| {
-                    digit
-                        .parse::<u8>()
-                        .map_err(|_| format!("{}: `{}` is not numerical", s, digit))
-                })
-                .collect()
+    fn for_each_digit<F>(base: u16, s: &str, digits: &str, mut f: F) -> Result<(), String>
+    where
+        F: FnMut(u16),
+    {
+        let parse_digit = |digit: &str| -> Result<u16, String> {
+            match digit.parse::<u16>() {
+                Ok(value) if value < base => Ok(value),
+                Ok(_) => Err(format!("{}: `{}` is not a digit in base {}", s, digit, base)),
+                Err(_) => Err(format!("{}: `{}` is not numerical", s, digit)),
+            }
         };
 
-        Ok((
-            parse_digits(parts[0])?,
-            parse_digits(parts.get(1).unwrap_or(&""))?,
-        ))
+        if base < 10 {
+            for (offset, c) in digits.char_indices() {
+                f(parse_digit(&digits[offset..offset + c.len_utf8()])?);
+            }
+        } else {
+            for digit in digits.split(',').filter(|digit| !digit.is_empty()) {
+                f(parse_digit(digit)?);
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn value_from_digits(base: u16, digits: &[u16]) -> Option<u128> {
+        digits.iter().try_fold(0u128, |sum, &digit| {
+            sum.checked_mul(base as u128)?.checked_add(digit as u128)
+        })
+    }
+
+    pub fn digits_from_value(base: u16, value: u128, len: u8) -> DigitBuffer {
+        let mut digits = DigitBuffer::zeros(len as usize);
+        let mut remaining = value;
+        for digit in digits.iter_mut().rev() {
+            *digit = (remaining % base as u128) as u16;
+            remaining /= base as u128;
+        }
+        digits
+    }
+
+    pub fn digits_from_rational<N>(base: u16, value: &Ratio<N>) -> (Vec<u16>, Vec<u16>)
+    where
+        N: Clone + Integer + Hash + From<u16> + ToPrimitive,
+    {
+        let denominator = value.denom();
+        let mut remainder = value.numer().mod_floor(denominator);
+        let mut digits = vec![];
+        let mut seen_remainders = HashMap::new();
+
+        while !remainder.is_zero() {
+            if let Some(&cycle_start) = seen_remainders.get(&remainder) {
+                let repeating_digits = digits.split_off(cycle_start);
+                return (digits, repeating_digits);
+            }
+            seen_remainders.insert(remainder.clone(), digits.len());
+
+            let (digit, next_remainder) = (remainder * N::from(base)).div_mod_floor(denominator);
+            digits.push(digit.to_u16().unwrap());
+            remainder = next_remainder;
+        }
+
+        (digits, vec![])
     }
 
-    pub fn value_from_digits(base: u8, digits: &[u8]) -> u128 {
+    pub fn digits_to_string(base: u16, digits: &[u16]) -> String {
+        let digit_joiner = if base < 10 { "" } else { "," };
         digits
             .iter()
-            .rev()
-            .fold((0, 1), |(sum, exp), &digit| {
-                (sum + (digit as u128) * exp, exp * (base as u128))
-            })
-            .0
+            .map(|digit| digit.to_string())
+            .collect::<Vec<String>>()
+            .join(digit_joiner)
     }
 
     #[cfg(test)]
     mod tests {
         use super::*;
 
+        use std::collections::HashSet;
+
         type Fraction = UnitFraction;
 
         #[test]
@@ -156,11 +953,11 @@ pub mod laminations {
                 ternary.parse("1_").unwrap(),
             }
             assert_eq! {
-                Fraction::new(9, 3, 0, 0),
+                Fraction::new(1, 1, 0, 0),
                 ternary.parse("100").unwrap(),
             }
             assert_eq! {
-                Fraction::new(9, 3, 0, 0),
+                Fraction::new(1, 1, 0, 0),
                 ternary.parse("100_").unwrap(),
             }
             assert_eq! {
@@ -173,7 +970,12 @@ pub mod laminations {
             }
 
             assert!(ternary.parse("1_100_").is_err());
-            assert!(ternary.parse("1_o1").is_err())
+            assert!(ternary.parse("1_o1").is_err());
+            assert_eq!(ternary.parse("5"), Err("5: `5` is not a digit in base 3".to_string()));
+            assert_eq!(
+                DefaultAlgebra::new(12).parse("_12"),
+                Err("_12: `12` is not a digit in base 12".to_string())
+            );
         }
 
         #[test]
@@ -202,6 +1004,164 @@ pub mod laminations {
             }
         }
 
+        #[test]
+        fn large_bases() {
+            let base = DefaultAlgebra::new(1000);
+            let point = base.parse("999_3,500").unwrap();
+
+            assert_eq!(point, Fraction::new(999, 1, 3500, 2));
+            assert_eq!(base.format(&point), "999_3,500");
+            assert_eq!(base.to_rational(&point), Ratio::new(999_002_501, 999_999_000));
+            assert_eq!(base.map(&point), base.parse("_3,500").unwrap());
+            assert_eq!(base.preimages(&point).unwrap().len(), 1000);
+            assert_eq!(base.period(&base.parse("_3,500").unwrap()), Some(2));
+
+            let wide = DefaultAlgebra::new(u16::MAX);
+            assert_eq!(
+                wide.format(&wide.parse("_65534,0").unwrap()),
+                "_65534,0"
+            );
+        }
+
+        #[test]
+        fn rejects_points_that_overflow_u128() {
+            let base = DefaultAlgebra::new(1000);
+            let error = base.parse("1,2,3,4,5,6,7,8,9,10,11,12,13").unwrap_err();
+            assert!(error.contains("do not fit in u128"), "{}", error);
+            assert!(base.parse("_1,2,3,4,5,6,7,8,9,10,11,12,13").is_err());
+
+            let longest = base.parse("1,1,1,1,1,1,1,1,1,1,1,1").unwrap();
+            assert!(base.preimages(&longest).is_err());
+
+            let binary = DefaultAlgebra::new(2);
+            assert!(binary.parse(&"1".repeat(127)).is_ok());
+            assert!(binary.parse(&"1".repeat(128)).is_err());
+            assert!(binary.parse(&format!("_{}", "01".repeat(100))).is_ok());
+        }
+
+        #[test]
+        fn validates_base() {
+            assert!(DefaultAlgebra::try_new(0).is_err());
+            assert!(DefaultAlgebra::try_new(1).is_err());
+            assert_eq!(DefaultAlgebra::try_new(2).unwrap().base, 2);
+            assert_eq!(DefaultAlgebra::try_new(255).unwrap().base, 255);
+        }
+
+        #[test]
+        #[should_panic]
+        fn new_panics_on_invalid_base() {
+            DefaultAlgebra::new(1);
+        }
+
+        #[test]
+        fn parse_digit_parts_rejects_malformed_input() {
+            assert!(parse_digit_parts(3, "1_0_1").is_err());
+            assert!(parse_digit_parts(3, "1_0x").is_err());
+            assert!(parse_digit_parts(12, "1,a").is_err());
+        }
+
+        #[test]
+        fn nary_macro() {
+            assert_eq!(nary!(3, "1_021"), Fraction::new(0, 0, 11, 3));
+            assert_eq!(nary!(12, "_11,9,2"), Fraction::new(0, 0, 1694, 3));
+
+            for _ in 0..2 {
+                assert_eq!(nary!(2, "_01"), Fraction::new(0, 0, 1, 2));
+            }
+
+            let mut evaluations = 0;
+            let mut next_base = || {
+                evaluations += 1;
+                3
+            };
+            let digits = String::from("1_021");
+            assert_eq!(nary!(next_base(), &digits), Fraction::new(0, 0, 11, 3));
+            assert_eq!(evaluations, 1);
+        }
+
+        #[test]
+        #[should_panic(expected = "nary!(3, \"1_o1\")")]
+        fn nary_macro_panics_on_malformed_literal() {
+            let _ = nary!(3, "1_o1");
+        }
+
+        #[test]
+        fn parse_configured() {
+            let ternary = DefaultAlgebra::new(3)
+                .digit_separator(' ')
+                .unwrap()
+                .trim_whitespace(true)
+                .accept_dot(true);
+            let dozenal = DefaultAlgebra::new(12).digit_separator(';').unwrap();
+
+            assert_eq!(
+                ternary.parse("  1 . 0 2 1\t").unwrap(),
+                Fraction::new(0, 0, 11, 3),
+            );
+            assert_eq!(ternary.parse("1_021").unwrap(), Fraction::new(0, 0, 11, 3));
+            assert_eq!(
+                dozenal.parse("11_11;9;2").unwrap(),
+                Fraction::new(11, 1, 1694, 3),
+            );
+            assert_eq!(
+                dozenal.parse("11_11,9,2").unwrap(),
+                Fraction::new(11, 1, 1694, 3),
+            );
+
+            assert!(DefaultAlgebra::new(3).parse(" 1_021").is_err());
+            assert!(DefaultAlgebra::new(3).parse("1.021").is_err());
+            assert!(dozenal.parse("11.11;9;2").is_err());
+
+            for separator in ['_', '.', '0', '7'] {
+                assert_eq!(
+                    DefaultAlgebra::new(12).digit_separator(separator).err(),
+                    Some(format!("`{}` cannot be a digit separator", separator))
+                );
+            }
+        }
+
+        #[test]
+        fn read_lines() {
+            let binary = DefaultAlgebra::new(2);
+            let input = "_001 _010\n\n  _1\t1_01\n_01 1_x\n_01\n_0 _1 _01\n";
+
+            let lines: Vec<Result<Leaf<Fraction>, String>> =
+                binary.read_lines(input.as_bytes()).collect();
+
+            assert_eq!(lines.len(), 5);
+            assert_eq!(
+                lines[0].as_ref().unwrap(),
+                &(Fraction::new(0, 0, 1, 3), Fraction::new(0, 0, 2, 3)),
+            );
+            assert_eq!(
+                lines[1].as_ref().unwrap(),
+                &(Fraction::new(0, 0, 0, 0), Fraction::new(0, 0, 2, 2)),
+            );
+            assert!(lines[2]
+                .as_ref()
+                .unwrap_err()
+                .starts_with("line 4, column 5:"));
+            assert_eq!(lines[3], Err("line 5: expected 2 points, found 1".to_string()));
+            assert_eq!(lines[4], Err("line 6: expected 2 points, found 3".to_string()));
+        }
+
+        #[test]
+        fn parse_all() {
+            let ternary = DefaultAlgebra::new(3);
+
+            assert_eq!(
+                ternary.parse_all(vec!["1_", "_21"]).unwrap(),
+                vec![Fraction::new(1, 1, 0, 0), Fraction::new(0, 0, 7, 2)],
+            );
+            assert_eq!(ternary.parse_all(vec![]).unwrap(), vec![]);
+
+            let errors = ternary.parse_all(vec!["1_", "1_1_1", "2", "x"]).unwrap_err();
+            assert_eq!(
+                errors.iter().map(|(index, _)| *index).collect::<Vec<usize>>(),
+                vec![1, 3],
+            );
+        }
+
         #[test]
         fn simplifies() {
             let ternary = DefaultAlgebra::new(3);
@@ -237,5 +1197,267 @@ pub mod laminations {
             let g2 = ternary.parse("_2").unwrap();
             assert_eq!(g1, g2);
         }
+
+        #[test]
+        fn normalizes() {
+            let ternary = DefaultAlgebra::new(3);
+            let normal_form = |s: &str| ternary.format(&ternary.parse(s).unwrap());
+
+            assert_eq!(normal_form("1_2"), "2_");
+            assert_eq!(normal_form("12_2"), "2_");
+            assert_eq!(normal_form("22_2"), "_");
+            assert_eq!(normal_form("0110_0"), "011_");
+            assert_eq!(normal_form("_1212"), "_12");
+            assert_eq!(normal_form("2_1212"), "_21");
+
+            assert_eq!(
+                UnitFraction::normalized(3, 7, 2, 0, 0),
+                Ok(ternary.parse("21").unwrap())
+            );
+            assert_eq!(
+                UnitFraction::normalized(3, 100, 1, 0, 0),
+                Err("100 does not fit in 1 digits in base 3".to_string())
+            );
+            assert_eq!(
+                UnitFraction::normalized(2, 1, 0, 0, 0),
+                Err("1 does not fit in 0 digits in base 2".to_string())
+            );
+            assert_eq!(
+                ternary.preimages(&ternary.parse("_1").unwrap()).unwrap()[1],
+                ternary.parse("_1").unwrap()
+            );
+
+            let points: HashSet<UnitFraction> = ["_102", "1_021", "10_210", "_", "_2"]
+                .iter()
+                .map(|s| ternary.parse(s).unwrap())
+                .collect();
+            assert_eq!(points.len(), 2);
+        }
+
+        #[test]
+        fn reduces_digit_words() {
+            let reduce = |base: u16, exact: &[u16], repeating: &[u16]| {
+                let (exact, repeating) = reduce_digits(base, exact, repeating).unwrap();
+                (exact.to_vec(), repeating.to_vec())
+            };
+
+            assert_eq!(reduce(3, &[1, 0], &[2, 1, 0, 2, 1, 0]), (vec![], vec![1, 0, 2]));
+            assert_eq!(reduce(3, &[1, 2], &[2]), (vec![2], vec![]));
+            assert_eq!(reduce(3, &[2, 2], &[2, 2]), (vec![], vec![]));
+            assert_eq!(reduce(2, &[0, 1, 1], &[0]), (vec![0, 1, 1], vec![]));
+            assert_eq!(reduce(2, &[], &[]), (vec![], vec![]));
+            assert_eq!(
+                reduce_digits(3, &[1], &[3]).err(),
+                Some("3 is not a digit in base 3".to_string())
+            );
+        }
+
+        #[test]
+        fn formats() {
+            let ternary = DefaultAlgebra::new(3);
+            let dozenal = DefaultAlgebra::new(12);
+
+            let a = ternary.parse("1_021").unwrap();
+            assert_eq!(ternary.format(&a), "_102");
+            assert_eq!(ternary.format_fraction(&a), "11/26");
+            assert_eq!(ternary.format_digits(&a), (vec![], vec![1, 0, 2]));
+
+            let b = ternary.parse("002").unwrap();
+            assert_eq!(ternary.format(&b), "002_");
+            assert_eq!(ternary.format_fraction(&b), "2/27");
+
+            let c = dozenal.parse("11_0,9,2").unwrap();
+            assert_eq!(dozenal.format(&c), "11_0,9,2");
+            assert_eq!(dozenal.format_digits(&c), (vec![11], vec![0, 9, 2]));
+
+            assert_eq!(ternary.format(&ternary.parse("_").unwrap()), "_");
+            assert_eq!(ternary.format_fraction(&ternary.parse("_").unwrap()), "0/1");
+        }
+
+        #[test]
+        fn dynamics() {
+            let binary = DefaultAlgebra::new(2);
+            let ternary = DefaultAlgebra::new(3);
+
+            let a = ternary.parse("12_021").unwrap();
+            assert_eq!(ternary.map(&a), ternary.parse("2_021").unwrap());
+            assert_eq!(ternary.map(&ternary.map(&a)), ternary.parse("_021").unwrap());
+            assert_eq!(
+                ternary.map(&ternary.parse("_021").unwrap()),
+                ternary.parse("_210").unwrap()
+            );
+
+            let preimages = ternary.preimages(&ternary.parse("2_1").unwrap()).unwrap();
+            assert_eq!(
+                preimages,
+                vec![
+                    ternary.parse("02_1").unwrap(),
+                    ternary.parse("12_1").unwrap(),
+                    ternary.parse("22_1").unwrap(),
+                ]
+            );
+            for preimage in &preimages {
+                assert_eq!(ternary.map(preimage), ternary.parse("2_1").unwrap());
+            }
+
+            let b = binary.parse("11_001").unwrap();
+            assert_eq!(
+                binary.orbit(&b),
+                vec![
+                    b.clone(),
+                    binary.parse("_100").unwrap(),
+                    binary.parse("_001").unwrap(),
+                    binary.parse("_010").unwrap(),
+                ]
+            );
+            assert_eq!(binary.orbit_bounded(&b, 4), Some(binary.orbit(&b)));
+            assert_eq!(binary.orbit_bounded(&b, 3), None);
+            assert_eq!(binary.period(&b), None);
+            assert_eq!(binary.period(&binary.parse("_001").unwrap()), Some(3));
+            assert_eq!(binary.period(&binary.parse("_").unwrap()), Some(1));
+        }
+
+        #[test]
+        fn converts() {
+            let ternary = DefaultAlgebra::new(3);
+
+            let a = UnitFraction::try_from(InBase::new(3, Ratio::new(11, 26))).unwrap();
+            assert_eq!(ternary.format(&a), "_102");
+            assert_eq!(Ratio::from(InBase::new(3, a.clone())), Ratio::new(11, 26));
+            assert_eq!(f64::from(InBase::new(3, a.clone())), 11.0 / 26.0);
+
+            let b = UnitFraction::try_from(InBase::new(2, 0.375)).unwrap();
+            assert_eq!(b.clone(), Fraction::new(3, 3, 0, 0));
+            assert!(UnitFraction::try_from(InBase::new(2, f64::NAN)).is_err());
+            assert!(UnitFraction::try_from(InBase::new(2, Ratio::new(1, 1009))).is_err());
+
+            assert_eq!(InBase::new(3, ternary.parse("_1").unwrap()).to_f64(), Some(0.5));
+            assert_eq!(InBase::<UnitFraction>::from_f64(1.375), Some(InBase::new(2, b)));
+            assert_eq!(
+                InBase::<UnitFraction>::from_u64(7),
+                Some(InBase::new(2, Fraction::new(0, 0, 0, 0)))
+            );
+            assert_eq!(InBase::new(3, a).to_u64(), Some(0));
+            assert_eq!(
+                InBase::<UnitFraction>::try_from(f64::INFINITY),
+                Err("inf is not representable as a InBase<UnitFraction>".to_string())
+            );
+        }
+
+        #[test]
+        fn cached_powers() {
+            let binary = Powers::new(2);
+            assert_eq!(binary.pow(0), 1);
+            assert_eq!(binary.pow(10), 1024);
+            assert_eq!(binary.pow(127), 1 << 127);
+            assert_eq!(binary.repeating_denominator(0), 1);
+            assert_eq!(binary.repeating_denominator(3), 7);
+
+            let ternary = DefaultAlgebra::new(3);
+            for s in &["_", "2", "1_021", "_102", "0012_11"] {
+                let x = ternary.parse(s).unwrap();
+                assert_eq!(ternary.to_rational(&x), x.to_rational(3));
+            }
+        }
+
+        #[test]
+        #[should_panic(expected = "2^128 overflows u128")]
+        fn cached_powers_overflow() {
+            Powers::new(2).pow(128);
+        }
+
+        #[test]
+        fn small_rational_fast_path() {
+            let binary = DefaultAlgebra::new(2);
+            let ternary = DefaultAlgebra::new(3);
+
+            for s in &["_", "1", "0011_01", "_0001011", "1_1100"] {
+                let x = ternary.parse(s).unwrap();
+                let expected = BigPoint::parse_nary(3, s).unwrap().to_rational(3);
+                assert_eq!(x.to_small_rational(3), Some(expected));
+                assert_eq!(ternary.to_rational(&x), expected);
+            }
+
+            let small = binary.parse(&format!("_{}1", "0".repeat(62))).unwrap();
+            assert_eq!(small.to_rational(2), Ratio::new(1, (1 << 63) - 1));
+
+            let large = binary.parse(&format!("1_{}1", "0".repeat(70))).unwrap();
+            assert!(large.to_small_rational(2).is_none());
+            assert_eq!(
+                large.to_rational(2),
+                Ratio::new(1, 2) + Ratio::new(1, 2 * ((1 << 71) - 1))
+            );
+            assert_eq!(large.to_rational(2), binary.to_rational(&large));
+        }
+
+        #[test]
+        fn batch_dynamics() {
+            let ternary = DefaultAlgebra::new(3);
+            let points = ternary.parse_all(vec!["_01", "2_1", "_"]).unwrap();
+
+            assert_eq!(
+                ternary.map_batch(&points),
+                points.iter().map(|x| ternary.map(x)).collect::<Vec<_>>()
+            );
+            assert_eq!(
+                ternary.preimages_batch(&points).unwrap(),
+                points.iter().flat_map(|x| ternary.preimages(x).unwrap()).collect::<Vec<_>>()
+            );
+
+            let mut buffer = ternary.parse_all(vec!["1", "2"]).unwrap();
+            ternary.map_batch_into(&points[..1], &mut buffer);
+            assert_eq!(buffer, vec![ternary.parse("_10").unwrap()]);
+            ternary.preimages_batch_into(&points[2..], &mut buffer).unwrap();
+            assert_eq!(buffer, ternary.parse_all(vec!["_", "1", "2"]).unwrap());
+        }
+
+        #[test]
+        fn compares_in_base() {
+            let ternary = DefaultAlgebra::new(3);
+
+            let a = ternary.parse("_1").unwrap();
+            let b = ternary.parse("2_").unwrap();
+            assert_eq!(ternary.cmp(&a, &b), Ordering::Less);
+            assert_eq!(ternary.cmp(&b, &a), Ordering::Greater);
+            assert_eq!(
+                ternary.cmp(&a, &ternary.parse("1_1").unwrap()),
+                Ordering::Equal
+            );
+        }
+
+        #[test]
+        fn crosses_in_either_orientation() {
+            let binary = DefaultAlgebra::new(2);
+            let flip = |(a, b): Leaf<UnitFraction>| (b, a);
+            let orientations = |a: Leaf<UnitFraction>, b: Leaf<UnitFraction>| {
+                vec![
+                    (a.clone(), b.clone()),
+                    (flip(a.clone()), b.clone()),
+                    (a.clone(), flip(b.clone())),
+                    (flip(a), flip(b)),
+                ]
+            };
+
+            let linked = test_support::pair(&binary, "_001", "_100");
+            let other = test_support::pair(&binary, "_010", "_101");
+            for (a, b) in orientations(linked, other) {
+                assert!(binary.crosses(&a, &b));
+                assert!(binary.crosses(&b, &a));
+            }
+
+            // The second leaf spans the arc through 0 once reversed.
+            let inner = test_support::pair(&binary, "_01", "_10");
+            let outer = test_support::pair(&binary, "0_01", "1_10");
+            for (a, b) in orientations(inner.clone(), outer) {
+                assert!(!binary.crosses(&a, &b));
+                assert!(!binary.crosses(&b, &a));
+            }
+
+            let wrapped = test_support::pair(&binary, "1_10", "_001");
+            assert!(binary.crosses(&inner, &test_support::pair(&binary, "_", "1")));
+            assert!(!binary.crosses(&inner, &wrapped));
+            assert!(!binary.crosses(&flip(wrapped.clone()), &inner));
+            assert!(binary.crosses(&wrapped, &test_support::pair(&binary, "_", "1")));
+        }
     }
 }